@@ -1,14 +1,129 @@
+use log::warn;
+use std::cell::Cell;
+
 use crate::components::*;
 use crate::system::System;
 
+// how a PS/2 keyboard's clock and data lines reach the VIA
+#[derive(Clone, Copy, Debug)]
+pub enum KeyboardWiring {
+    Ca1,           // clock on CA1, data on PA4, read by the CA1 interrupt handler
+    ShiftRegister, // clock on CB1 and data on CB2, shifted in by the VIA's shift register
+}
+
+// a pin of one of the board's VIAs, numbered from via0
+#[derive(Clone, Copy, Debug)]
+pub struct PortPin {
+    pub via: usize,
+    pub port: Port,
+    pub pin: u8,
+}
+
+impl PortPin {
+    // "via1:pa" for a whole port, given as pin 0
+    pub fn parse_port(s: &str) -> Option<PortPin> {
+        Self::parse(&format!("{}0", s))
+    }
+
+    // e.g. "via0:pb3"
+    pub fn parse(s: &str) -> Option<PortPin> {
+        let ix = s.find(':')?;
+        let via = s[..ix].strip_prefix("via")?.parse().ok()?;
+        let pin = &s[ix + 1..];
+        let port = match pin.get(..2)? {
+            "pa" => Port::A,
+            "pb" => Port::B,
+            _ => return None,
+        };
+        match pin[2..].parse() {
+            Ok(pin) if pin < 8 => Some(PortPin { via, port, pin }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum DisplayWiring {
+    Bus8,        // D7-D0 on PB7-PB0
+    UpperNibble, // D7-D4 on PB7-PB4, leaving PB3-PB0 free
+}
+
+pub struct BoardOptions {
+    pub clock_hz: u32,
+    pub display_wiring: DisplayWiring,
+    pub display_geometry: Geometry,
+    pub display_rom: CharacterRom,
+    pub display_busy_check: BusyCheck,
+    pub controllers: [Option<ControllerType>; 2],
+    pub keyboard: Option<KeyboardWiring>,
+    pub keypad: Option<PortPin>, // rows on pins 0-3 of the port, columns on pins 4-7
+    pub buttons: Vec<PortPin>,
+    pub button_bounce_micros: u32,
+    pub leds: Vec<PortPin>,
+    pub led_bar: Option<PortPin>,  // eight LEDs on a port, pin 0 at the left
+    pub segments: Option<PortPin>, // 7-segment digits' segments a-g and DP on pins 0-7 of a port
+    pub digits: Vec<PortPin>,      // each digit's common cathode, leftmost first
+    pub pull_ups: Vec<PortPin>,    // resistors holding pins while nothing drives them
+    pub pull_downs: Vec<PortPin>,
+}
+
+impl Default for BoardOptions {
+    fn default() -> BoardOptions {
+        BoardOptions {
+            clock_hz: 1_000_000,
+            display_wiring: DisplayWiring::Bus8,
+            display_geometry: Geometry { columns: 16, rows: 2 },
+            display_rom: CharacterRom::A00,
+            display_busy_check: BusyCheck::Off,
+            controllers: [Some(ControllerType::Snes), Some(ControllerType::Snes)],
+            keyboard: None,
+            keypad: None,
+            buttons: Vec::new(),
+            button_bounce_micros: 0,
+            leds: Vec::new(),
+            led_bar: None,
+            segments: None,
+            digits: Vec::new(),
+            pull_ups: Vec::new(),
+            pull_downs: Vec::new(),
+        }
+    }
+}
+
 pub struct BreadboardSystem {
     cpu: W65C02S<SystemBus>,
+    clock_hz: u32,
 }
 
 impl BreadboardSystem {
-    pub fn new(rom_path: &str) -> BreadboardSystem {
+    pub fn new(rom_path: &str, options: &BoardOptions) -> BreadboardSystem {
+        BreadboardSystem {
+            cpu: W65C02S::new(SystemBus::new(rom_path, vec![
+                PeripheralController::via("via0", SystemBus::VIA0_SELECTOR, Peripherals::new(options).with_devices(0, options)),
+            ])),
+            clock_hz: options.clock_hz,
+        }
+    }
+
+    // a board with the 128x64 graphic LCD in place of the character LCD
+    pub fn new_graphic(rom_path: &str, options: &BoardOptions) -> BreadboardSystem {
+        BreadboardSystem {
+            cpu: W65C02S::new(SystemBus::new(rom_path, vec![
+                PeripheralController::via("via0", SystemBus::VIA0_SELECTOR, Peripherals::graphic(options).with_devices(0, options)),
+            ])),
+            clock_hz: options.clock_hz,
+        }
+    }
+
+    pub fn new_expanded(rom_path: &str, options: &BoardOptions, serial: Box<dyn SerialLink>) -> BreadboardSystem {
         BreadboardSystem {
-            cpu: W65C02S::new(SystemBus::new(rom_path)),
+            cpu: W65C02S::new(SystemBus::new(rom_path, vec![
+                PeripheralController::via("via0", SystemBus::VIA0_SELECTOR, Peripherals::new(options).with_devices(0, options)),
+                PeripheralController::via("via1", SystemBus::VIA1_SELECTOR, Peripherals::unconnected().with_devices(1, options)),
+                PeripheralController::acia("acia0", SystemBus::ACIA0_SELECTOR, serial, options.clock_hz),
+                PeripheralController::cia("cia0", SystemBus::CIA0_SELECTOR, Peripherals::unconnected(), options.clock_hz),
+            ])),
+            clock_hz: options.clock_hz,
         }
     }
 }
@@ -21,40 +136,198 @@ impl System for BreadboardSystem {
         self.cpu.is_halted()
     }
 
+    fn get_clock_hz(&self) -> u32 {
+        self.clock_hz
+    }
+
     fn get_cpu(&self) -> &W65C02S<Self::BusType> {
         &self.cpu
     }
 
     fn get_display(&mut self) -> Option<&mut HD44780U> {
-        Some(&mut self.cpu.bus.per.ports.dsp)
+        self.cpu.bus.pers.iter_mut().find_map(|p| Some(&mut p.ports_mut()?.dsp.as_mut()?.dev))
+    }
+
+    fn get_graphic_display(&mut self) -> Option<&mut KS0108> {
+        self.cpu.bus.pers.iter_mut().find_map(|p| Some(&mut p.ports_mut()?.glcd.as_mut()?.dev))
     }
 
     fn get_ram(&self) -> &RAM {
         &self.cpu.bus.ram
     }
 
-    fn get_controller(&mut self) -> Option<&mut SNESController> {
-        Some(&mut self.cpu.bus.per.ports.con)
+    fn get_controller(&mut self, port: usize) -> Option<&mut dyn Controller> {
+        self.cpu.bus.pers.iter_mut()
+            .find_map(|p| p.ports_mut()?.changing().con[port].as_mut())
+            .map(|con| -> &mut dyn Controller { con.dev.as_mut() })
+    }
+
+    fn get_keyboard(&mut self) -> Option<&mut PS2Keyboard> {
+        self.cpu.bus.pers.iter_mut().find_map(|p| Some(&mut p.ports_mut()?.changing().kbd.as_mut()?.dev))
+    }
+
+    fn get_keypad(&mut self) -> Option<&mut MatrixKeypad> {
+        self.cpu.bus.pers.iter_mut().find_map(|p| Some(&mut p.ports_mut()?.changing().keypad.as_mut()?.dev))
+    }
+
+    fn get_push_button(&mut self, n: usize) -> Option<&mut PushButton> {
+        self.cpu.bus.pers.iter_mut().find_map(|p| {
+            p.ports_mut()?.changing().buttons.iter_mut().find(|(ix, _)| *ix == n).map(|(_, btn)| &mut btn.dev)
+        })
+    }
+
+    fn get_lights(&mut self) -> Vec<&mut Lights> {
+        self.cpu.bus.pers.iter_mut()
+            .filter_map(|p| p.ports_mut())
+            .flat_map(|ports| {
+                ports.leds.iter_mut().map(|w| &mut w.dev).chain(ports.digits.iter_mut().map(|w| &mut w.dev))
+            })
+            .collect()
+    }
+
+    fn get_serial(&mut self) -> Option<&mut W65C51> {
+        self.cpu.bus.pers.iter_mut().find_map(|p| match &mut p.chip {
+            PeripheralChip::Acia(acia) => Some(acia),
+            _ => None,
+        })
+    }
+
+    fn peek_serial(&self) -> Option<&W65C51> {
+        self.cpu.bus.pers.iter().find_map(|p| match &p.chip {
+            PeripheralChip::Acia(acia) => Some(acia),
+            _ => None,
+        })
+    }
+
+    fn get_peripheral_controllers(&self) -> Vec<(&str, &W65C22<Self::PortsType>)> {
+        self.cpu.bus.pers.iter().filter_map(|p| match &p.chip {
+            PeripheralChip::Via(via) => Some((p.name, via)),
+            _ => None,
+        }).collect()
     }
 
-    fn get_peripheral_controller(&self) -> Option<&W65C22<Self::PortsType>> {
-        Some(&self.cpu.bus.per)
+    fn get_interface_adapters(&self) -> Vec<(&str, &MOS6526<Self::PortsType>)> {
+        self.cpu.bus.pers.iter().filter_map(|p| match &p.chip {
+            PeripheralChip::Cia(cia) => Some((p.name, cia)),
+            _ => None,
+        }).collect()
     }
 
     fn peek(&mut self, addr: u16) -> u8 {
         self.cpu.bus.peek(addr)
     }
 
+    // the reset button is wired to the CPU only, so peripherals keep their state
+    fn reset(&mut self) {
+        self.cpu.reset();
+    }
+
     fn cycle(&mut self) {
         self.cpu.cycle();
-        let per_int = self.cpu.bus.per.cycle();
+
+        // the IRQ outputs are open-drain and wired together onto the CPU's IRQ line
+        let mut per_int = false;
+        for per in self.cpu.bus.pers.iter_mut() {
+            per_int |= per.cycle();
+            if let Some(ports) = per.ports_mut() {
+                ports.cycle();
+            }
+        }
         self.cpu.set_interrupt(per_int);
-        self.cpu.bus.per.ports.dsp.cycle();
+    }
+}
+
+pub enum PeripheralChip {
+    Via(W65C22<Peripherals>),
+    Cia(MOS6526<Peripherals>),
+    Acia(W65C51),
+}
+
+pub struct PeripheralController {
+    pub name: &'static str,
+    pub selector: (u16, u16),
+    pub chip: PeripheralChip,
+}
+
+impl PeripheralController {
+    // the TOD input of a CIA is fed from 60Hz mains
+    const TOD_HZ: u32 = 60;
+    // an ACIA's RxC input takes its crystal's output, so the 16x external clock setting gives 115200
+    // baud
+    const ACIA_RXC_HZ: u32 = 1_843_200;
+
+    pub fn via(name: &'static str, selector: (u16, u16), ports: Peripherals) -> PeripheralController {
+        PeripheralController {
+            name,
+            selector,
+            chip: PeripheralChip::Via(W65C22::new(ports)),
+        }
+    }
+
+    pub fn cia(name: &'static str, selector: (u16, u16), mut ports: Peripherals, clock_hz: u32) -> PeripheralController {
+        ports.pins = PortPins::cia();
+        PeripheralController {
+            name,
+            selector,
+            chip: PeripheralChip::Cia(MOS6526::new(ports, clock_hz / Self::TOD_HZ)),
+        }
+    }
+
+    pub fn acia(name: &'static str, selector: (u16, u16), link: Box<dyn SerialLink>, clock_hz: u32) -> PeripheralController {
+        PeripheralController {
+            name,
+            selector,
+            chip: PeripheralChip::Acia(W65C51::new(link, clock_hz, Self::ACIA_RXC_HZ)),
+        }
+    }
+
+    pub fn ports_mut(&mut self) -> Option<&mut Peripherals> {
+        match &mut self.chip {
+            PeripheralChip::Via(via) => Some(&mut via.ports),
+            PeripheralChip::Cia(cia) => Some(&mut cia.ports),
+            PeripheralChip::Acia(_) => None,
+        }
+    }
+
+    fn is_selected(&self, addr: u16) -> bool {
+        addr & self.selector.0 == self.selector.1
+    }
+
+    fn peek(&self, addr: u16) -> u8 {
+        match &self.chip {
+            PeripheralChip::Via(via) => via.peek(addr & !self.selector.0),
+            PeripheralChip::Cia(cia) => cia.peek(addr & !self.selector.0),
+            PeripheralChip::Acia(acia) => acia.peek(addr & !self.selector.0),
+        }
+    }
+
+    fn read(&mut self, addr: u16) -> u8 {
+        match &mut self.chip {
+            PeripheralChip::Via(via) => via.read(addr & !self.selector.0),
+            PeripheralChip::Cia(cia) => cia.read(addr & !self.selector.0),
+            PeripheralChip::Acia(acia) => acia.read(addr & !self.selector.0),
+        }
+    }
+
+    fn write(&mut self, addr: u16, val: u8) {
+        match &mut self.chip {
+            PeripheralChip::Via(via) => via.write(addr & !self.selector.0, val),
+            PeripheralChip::Cia(cia) => cia.write(addr & !self.selector.0, val),
+            PeripheralChip::Acia(acia) => acia.write(addr & !self.selector.0, val),
+        }
+    }
+
+    fn cycle(&mut self) -> bool {
+        match &mut self.chip {
+            PeripheralChip::Via(via) => via.cycle(),
+            PeripheralChip::Cia(cia) => cia.cycle(),
+            PeripheralChip::Acia(acia) => acia.cycle(),
+        }
     }
 }
 
 pub struct SystemBus {
-    pub per: W65C22<Peripherals>,
+    pub pers: Vec<PeripheralController>,
     pub ram: RAM,
     pub rom: ROM,
 }
@@ -62,13 +335,16 @@ pub struct SystemBus {
 impl SystemBus {
     const ROM_SELECTOR: (u16, u16) = (0x8000, 0x8000);
     const RAM_SELECTOR: (u16, u16) = (0xC000, 0x0000);
-    const PER_SELECTOR: (u16, u16) = (0xFFF0, 0x6000);
+    const VIA0_SELECTOR: (u16, u16) = (0xFFF0, 0x6000);
+    const VIA1_SELECTOR: (u16, u16) = (0xFFF0, 0x7000);
+    const CIA0_SELECTOR: (u16, u16) = (0xFFF0, 0x4000);
+    const ACIA0_SELECTOR: (u16, u16) = (0xFFFC, 0x5000);
 
-    pub fn new(rom_path: &str) -> SystemBus {
+    pub fn new(rom_path: &str, pers: Vec<PeripheralController>) -> SystemBus {
         SystemBus {
             rom: ROM::load(rom_path),
             ram: RAM::new(0x4000),
-            per: W65C22::new(Peripherals::new()),
+            pers,
         }
     }
 }
@@ -79,8 +355,8 @@ impl Bus for SystemBus {
             self.rom.peek(addr & !Self::ROM_SELECTOR.0)
         } else if addr & Self::RAM_SELECTOR.0 == Self::RAM_SELECTOR.1 {
             self.ram.peek(addr & !Self::RAM_SELECTOR.0)
-        } else if addr & Self::PER_SELECTOR.0 == Self::PER_SELECTOR.1 {
-            self.per.peek(addr & !Self::PER_SELECTOR.0)
+        } else if let Some(per) = self.pers.iter().find(|p| p.is_selected(addr)) {
+            per.peek(addr)
         } else {
             panic!("peek at unmapped address: {:02x}", addr);
         }
@@ -91,8 +367,8 @@ impl Bus for SystemBus {
             self.rom.read(addr & !Self::ROM_SELECTOR.0)
         } else if addr & Self::RAM_SELECTOR.0 == Self::RAM_SELECTOR.1 {
             self.ram.read(addr & !Self::RAM_SELECTOR.0)
-        } else if addr & Self::PER_SELECTOR.0 == Self::PER_SELECTOR.1 {
-            self.per.read(addr & !Self::PER_SELECTOR.0)
+        } else if let Some(per) = self.pers.iter_mut().find(|p| p.is_selected(addr)) {
+            per.read(addr)
         } else {
             panic!("read at unmapped address: {:02x}", addr);
         }
@@ -103,98 +379,307 @@ impl Bus for SystemBus {
             self.rom.write(addr & !Self::ROM_SELECTOR.1, val);
         } else if addr & Self::RAM_SELECTOR.0 == Self::RAM_SELECTOR.1 {
             self.ram.write(addr & !Self::RAM_SELECTOR.1, val);
-        } else if addr & Self::PER_SELECTOR.0 == Self::PER_SELECTOR.1 {
-            self.per.write(addr & !Self::PER_SELECTOR.0, val);
+        } else if let Some(per) = self.pers.iter_mut().find(|p| p.is_selected(addr)) {
+            per.write(addr, val);
         } else {
             panic!("write at unmapped address: {:02x}", addr);
         }
     }
 }
 
+
+// the devices wired to one VIA's pins.  each device drives and reads its own nets, and the VIA
+// sees the level they resolve to, so devices can share a port.
 pub struct Peripherals {
-    pub dsp: HD44780U,
-    pub con: SNESController,
-    pub a_cache: u8,
-    pub b_cache: u8,
+    pub dsp: Option<Wired<HD44780U, LcdPins>>,
+    pub glcd: Option<Wired<KS0108, GlcdPins>>,
+    pub con: [Option<Wired<Box<dyn Controller>, ControllerPins>>; 2],
+    pub kbd: Option<Wired<PS2Keyboard, KeyboardPins>>,
+    pub keypad: Option<Wired<MatrixKeypad, KeypadPins>>,
+    pub buttons: Vec<(usize, Wired<PushButton, Pin>)>, // button number, and the button
+    pub leds: Vec<Wired<Lights, LedPins>>,
+    pub digits: Option<Wired<Lights, SegmentPins>>,
+    pub pins: PortPins,
+    nets: Cell<Option<Nets>>, // the resolved nets, until something changes them
 }
 
 impl Peripherals {
-    pub fn new() -> Peripherals {
+    // RS, R/W and E on PA5-PA7, data on port B
+    fn get_dsp_pins(data: [Option<Pin>; 8]) -> LcdPins {
+        LcdPins {
+            rs: Pin::port(Port::A, 5),
+            rw: Pin::port(Port::A, 6),
+            e: Pin::port(Port::A, 7),
+            data,
+        }
+    }
+
+    // each controller drives its own data pin, sharing the latch and clock
+    fn get_con_pins(port: usize) -> ControllerPins {
+        const DATA: [u8; 2] = [0, 3];
+
+        ControllerPins {
+            latch: Pin::port(Port::A, 1),
+            clk: Pin::port(Port::A, 2),
+            data: Pin::port(Port::A, DATA[port]),
+        }
+    }
+
+    fn get_kbd_pins(wiring: KeyboardWiring) -> KeyboardPins {
+        match wiring {
+            KeyboardWiring::Ca1 => KeyboardPins { clock: Pin::CA1, data: Pin::port(Port::A, 4) },
+            KeyboardWiring::ShiftRegister => KeyboardPins { clock: Pin::CB1, data: Pin::CB2 },
+        }
+    }
+
+    pub fn new(options: &BoardOptions) -> Peripherals {
+        let data = match options.display_wiring {
+            DisplayWiring::Bus8 => data_bus(Port::B, false),
+            DisplayWiring::UpperNibble => data_bus(Port::B, true),
+        };
+        let dsp = HD44780U::new(options.display_geometry, options.display_rom, options.clock_hz, options.display_busy_check);
+        let con = |port: usize| {
+            options.controllers[port].map(|t| Wired::new(t.create(), Self::get_con_pins(port)))
+        };
+
+        Peripherals {
+            dsp: Some(Wired::new(dsp, Self::get_dsp_pins(data))),
+            con: [con(0), con(1)],
+            kbd: options.keyboard.map(|wiring| Wired::new(PS2Keyboard::new(options.clock_hz), Self::get_kbd_pins(wiring))),
+            ..Self::unconnected()
+        }
+    }
+
+    // the graphic LCD shares RS, R/W and E with the character LCD it replaces, and its chip
+    // selects take the second controller's data pin and PA4, leaving only the first controller and
+    // a keyboard on the shift register lines
+    pub fn graphic(options: &BoardOptions) -> Peripherals {
+        let pins = GlcdPins {
+            lcd: Self::get_dsp_pins(data_bus(Port::B, false)),
+            cs: [Pin::port(Port::A, 3), Pin::port(Port::A, 4)],
+        };
+
+        Peripherals {
+            glcd: Some(Wired::new(KS0108::new(), pins)),
+            con: [options.controllers[0].map(|t| Wired::new(t.create(), Self::get_con_pins(0))), None],
+            kbd: options
+                .keyboard
+                .filter(|wiring| matches!(wiring, KeyboardWiring::ShiftRegister))
+                .map(|wiring| Wired::new(PS2Keyboard::new(options.clock_hz), Self::get_kbd_pins(wiring))),
+            ..Self::unconnected()
+        }
+    }
+
+    pub fn unconnected() -> Peripherals {
         Peripherals {
-            dsp: HD44780U::new(),
-            con: SNESController::new(),
-            a_cache: 0,
-            b_cache: 0,
+            dsp: None,
+            glcd: None,
+            con: [None, None],
+            kbd: None,
+            keypad: None,
+            buttons: Vec::new(),
+            leds: Vec::new(),
+            digits: None,
+            pins: PortPins::new(),
+            nets: Cell::new(None),
         }
     }
 
-    fn get_dsp_pins(&self) -> (RegisterSelector, bool, bool) {
-        const RS: u8 = 0x20;
-        const RW: u8 = 0x40;
-        const E: u8 = 0x80;
+    // adds the keypad, pushbuttons, LEDs, 7-segment digits and pull resistors wired to this VIA
+    pub fn with_devices(mut self, via: usize, options: &BoardOptions) -> Peripherals {
+        let pulls = |sites: &[PortPin]| {
+            sites.iter().filter(|site| site.via == via).fold(0, |acc, site| acc | Pin::port(site.port, site.pin).mask())
+        };
+        self.pins.pull_ups = pulls(&options.pull_ups);
+        self.pins.pull_downs = pulls(&options.pull_downs);
+        if let Some(site) = options.keypad.filter(|site| site.via == via) {
+            self.keypad = Some(Wired::new(MatrixKeypad::new(), KeypadPins::on_port(site.port)));
+        }
+        for (n, site) in options.buttons.iter().enumerate().filter(|(_, site)| site.via == via) {
+            let btn = PushButton::new(options.clock_hz, options.button_bounce_micros, n as u32 + 1);
+            self.buttons.push((n, Wired::new(btn, Pin::port(site.port, site.pin))));
+        }
 
-        (
-            if self.a_cache & RS == RS {
-                RegisterSelector::Data
-            } else {
-                RegisterSelector::Instruction
-            },
-            self.a_cache & RW == RW,
-            self.a_cache & E == E,
-        )
+        let leds: Vec<_> = options.leds.iter().filter(|site| site.via == via).map(|site| Pin::port(site.port, site.pin)).collect();
+        if !leds.is_empty() {
+            let lights = Lights::new(LightsKind::Leds, 1, leds.len(), options.clock_hz);
+            self.leds.push(Wired::new(lights, LedPins { leds }));
+        }
+        if let Some(site) = options.led_bar.filter(|site| site.via == via) {
+            let leds = (0..8).map(|bit| Pin::port(site.port, bit)).collect();
+            self.leds.push(Wired::new(Lights::new(LightsKind::Bar, 1, 8, options.clock_hz), LedPins { leds }));
+        }
+        if let Some(site) = options.segments.filter(|site| site.via == via) {
+            let digits: Vec<_> = options.digits.iter().filter(|site| site.via == via).map(|site| Pin::port(site.port, site.pin)).collect();
+            let lights = Lights::new(LightsKind::SevenSegment, digits.len().max(1), 8, options.clock_hz);
+            let pins = SegmentPins { segments: data_bus(site.port, false), digits };
+            self.digits = Some(Wired::new(lights, pins));
+        }
+        self
     }
 
-    fn get_con_pins(&self) -> (bool, bool) {
-        const LATCH: u8 = 0x02;
-        const CLK: u8 = 0x04;
+    fn devices(&self) -> impl Iterator<Item = &dyn PinDevice> {
+        self.dsp.iter().map(|d| d as &dyn PinDevice)
+            .chain(self.glcd.iter().map(|d| d as &dyn PinDevice))
+            .chain(self.con.iter().flatten().map(|d| d as &dyn PinDevice))
+            .chain(self.kbd.iter().map(|d| d as &dyn PinDevice))
+            .chain(self.keypad.iter().map(|d| d as &dyn PinDevice))
+            .chain(self.buttons.iter().map(|(_, d)| d as &dyn PinDevice))
+            .chain(self.leds.iter().map(|d| d as &dyn PinDevice))
+            .chain(self.digits.iter().map(|d| d as &dyn PinDevice))
+    }
 
-        (
-            self.a_cache & LATCH == LATCH,
-            self.a_cache & CLK == CLK,
-        )
+    fn devices_mut(&mut self) -> impl Iterator<Item = &mut dyn PinDevice> {
+        self.dsp.iter_mut().map(|d| d as &mut dyn PinDevice)
+            .chain(self.glcd.iter_mut().map(|d| d as &mut dyn PinDevice))
+            .chain(self.con.iter_mut().flatten().map(|d| d as &mut dyn PinDevice))
+            .chain(self.kbd.iter_mut().map(|d| d as &mut dyn PinDevice))
+            .chain(self.keypad.iter_mut().map(|d| d as &mut dyn PinDevice))
+            .chain(self.buttons.iter_mut().map(|(_, d)| d as &mut dyn PinDevice))
+            .chain(self.leds.iter_mut().map(|d| d as &mut dyn PinDevice))
+            .chain(self.digits.iter_mut().map(|d| d as &mut dyn PinDevice))
     }
-}
 
-impl Ports for Peripherals {
-    fn peek(&self, port: Port) -> u8 {
-        match port {
-            Port::A => {
-                self.con.peek() & 0x07
-            }
-            Port::B => {
-                let (rs, rw, e) = self.get_dsp_pins();
-                self.dsp.peek(rs, rw, e)
-            }
+    fn resolve(&self) -> Nets {
+        let mut nets = self.pins.nets();
+        for dev in self.devices() {
+            nets.add(dev.drive(), dev.pull_ups(), dev.pull_downs());
         }
+        nets
     }
 
-    fn read(&mut self, port: Port) -> u8 {
-        match port {
-            Port::A => {
-                self.con.read() & 0x07
+    fn nets(&self) -> Nets {
+        match self.nets.get() {
+            Some(nets) => nets,
+            None => {
+                let nets = self.resolve();
+                self.nets.set(Some(nets));
+                nets
             }
-            Port::B => {
-                let (rs, rw, e) = self.get_dsp_pins();
-                self.dsp.read(rs, rw, e)
+        }
+    }
+
+    fn levels(&self) -> u32 {
+        self.nets().levels()
+    }
+
+    // for input from the host, which may change what a device drives
+    pub fn changing(&mut self) -> &mut Peripherals {
+        self.nets.set(None);
+        self
+    }
+
+    // devices with their own timing may change what they drive on any cycle
+    pub fn cycle(&mut self) {
+        let mut changed = false;
+        if let Some(dsp) = self.dsp.as_mut() {
+            let last = dsp.drive();
+            dsp.dev.cycle();
+            changed |= dsp.drive() != last;
+        }
+        if let Some(kbd) = self.kbd.as_mut() {
+            let last = kbd.drive();
+            kbd.dev.cycle();
+            changed |= kbd.drive() != last;
+        }
+        for (_, btn) in self.buttons.iter_mut() {
+            let last = btn.drive();
+            btn.dev.cycle();
+            changed |= btn.drive() != last;
+        }
+        for leds in self.leds.iter_mut() {
+            leds.dev.cycle();
+        }
+        if let Some(digits) = self.digits.as_mut() {
+            digits.dev.cycle();
+        }
+
+        if changed {
+            self.nets.set(None);
+        }
+    }
+
+    fn check_contention(&mut self) {
+        let nets = self.nets();
+        if nets.contended() != 0 {
+            let drivers: Vec<_> = self.devices().map(|dev| (dev.name(), dev.drive())).collect();
+            let first = self.pins.contention.is_none();
+            self.pins.check(&nets, &drivers);
+            if first {
+                warn!("pin contention: {}", self.pins.contention.as_ref().unwrap());
             }
         }
     }
 
+    // every device sees the nets after each change to the VIA's side of them.  what the devices
+    // drive in response is resolved, and checked for contention, when the nets are next needed.
+    fn update_devices(&mut self) {
+        self.check_contention();
+        let levels = self.levels();
+        for dev in self.devices_mut() {
+            dev.update(levels);
+        }
+        self.nets.set(None);
+    }
+}
+
+impl Ports for Peripherals {
+    fn peek(&self, port: Port) -> u8 {
+        port_byte(port, self.levels())
+    }
+
+    fn read(&mut self, port: Port) -> u8 {
+        self.check_contention();
+        self.peek(port)
+    }
+
     fn write(&mut self, port: Port, val: u8) {
-        match port {
-            Port::A => {
-                self.a_cache = val;
-            }
-            Port::B => {
-                self.b_cache = val;
-            }
+        self.pins.set_levels(port, val);
+        self.nets.set(None);
+        self.update_devices();
+    }
+
+    // devices only notice a change of direction if it changes a level
+    fn write_direction(&mut self, port: Port, outputs: u8) {
+        let last = self.levels();
+        self.pins.set_outputs(port, outputs);
+        self.nets.set(None);
+        if self.levels() != last {
+            self.update_devices();
+        } else {
+            self.check_contention();
         }
+    }
+
+    fn peek_control(&self, line: ControlLine) -> bool {
+        Pin::control(line).is_high(self.levels())
+    }
+
+    fn write_control(&mut self, line: ControlLine, val: bool) {
+        self.pins.set_control(Pin::control(line), val);
+        self.nets.set(None);
+        self.update_devices();
+    }
 
-        let (rs, rw, e) = self.get_dsp_pins();
-        self.dsp.write(rs, rw, e, self.b_cache);
+    fn peek_pins(&self) -> u32 {
+        self.levels()
+    }
 
-        
-        let (latch, clk) = self.get_con_pins();
-        self.con.write(latch, clk);
+    fn describe_pins(&self) -> Vec<String> {
+        let nets = self.nets();
+        let levels = nets.levels();
+        let mut line = format!("PINS  PA:{:02x}  PB:{:02x}", port_byte(Port::A, levels), port_byte(Port::B, levels));
+        for pin in self.pins.controls.iter() {
+            line.push_str(&format!("  {}:{}", *pin, nets.level(*pin)));
+        }
+        let mut lines = vec![line];
+        let floating = [Port::A, Port::B].map(|port| port_byte(port, nets.floating()));
+        if floating != [0, 0] {
+            lines.push(format!("floating  PA:{:02x}  PB:{:02x}", floating[0], floating[1]));
+        }
+        if let Some(contention) = &self.pins.contention {
+            lines.push(format!("contention: {} ({} times)", contention, self.pins.contentions));
+        }
+        lines
     }
-}
\ No newline at end of file
+}
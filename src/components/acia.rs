@@ -0,0 +1,192 @@
+use log::{debug, warn};
+use std::fmt;
+
+use crate::components::serial::SerialLink;
+
+// status register bits
+const STATUS_IRQ: u8 = 0x80;
+const STATUS_TDRE: u8 = 0x10;
+const STATUS_RDRF: u8 = 0x08;
+const STATUS_OVERRUN: u8 = 0x04;
+
+// command register bits
+const COMMAND_DTR: u8 = 0x01;
+const COMMAND_IRD: u8 = 0x02;
+const COMMAND_REM: u8 = 0x10;
+
+// control register bits
+const CONTROL_SBN: u8 = 0x80;
+
+// baud rates (x100) selected by settings 1-15 of the low nibble of the control register, from a
+// 1.8432MHz crystal.  setting 0 takes 16x the baud rate on the RxC pin instead.
+const BAUD_RATES: [u32; 15] = [
+    5000, 7500, 10992, 13458, 15000, 30000, 60000, 120000, 180000, 240000, 360000, 480000, 720000,
+    960000, 1920000,
+];
+
+pub struct W65C51 {
+    pub rdr: u8,
+    pub rsr: u8,
+    pub tdr: u8,
+    pub status: u8,
+    pub command: u8,
+    pub control: u8,
+    pub clock_hz: u32,
+    pub rxc_hz: u32, // the external clock on RxC
+    pub rx_cycles: u32,
+    pub tx_cycles: u32,
+    pub link: Box<dyn SerialLink>,
+}
+
+impl fmt::Debug for W65C51 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("W65C51")
+            .field("rdr", &self.rdr)
+            .field("tdr", &self.tdr)
+            .field("status", &self.status)
+            .field("command", &self.command)
+            .field("control", &self.control)
+            .finish()
+    }
+}
+
+impl W65C51 {
+    pub fn new(link: Box<dyn SerialLink>, clock_hz: u32, rxc_hz: u32) -> W65C51 {
+        W65C51 {
+            rdr: 0,
+            rsr: 0,
+            tdr: 0,
+            // the W65C51N transmitter data register empty bit is stuck on
+            status: STATUS_TDRE,
+            command: 0,
+            control: 0,
+            clock_hz,
+            rxc_hz,
+            rx_cycles: 0,
+            tx_cycles: 0,
+            link,
+        }
+    }
+
+    fn word_mask(&self) -> u8 {
+        0xff >> ((self.control >> 5) & 0x03)
+    }
+
+    // number of CPU cycles it takes to shift one frame at the configured baud rate
+    pub fn byte_cycles(&self) -> u32 {
+        let word_length = 8 - ((self.control >> 5) & 0x03) as u64;
+        let stop_bits = if self.control & CONTROL_SBN == CONTROL_SBN { 2 } else { 1 };
+        let frame_bits = 1 + word_length + stop_bits;
+        let baud = match self.control & 0x0f {
+            0 => self.rxc_hz as u64 * 100 / 16,
+            rate => BAUD_RATES[rate as usize - 1] as u64,
+        };
+        ((self.clock_hz as u64) * frame_bits * 100 / baud) as u32
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.command & COMMAND_DTR == COMMAND_DTR
+    }
+
+    pub fn is_transmitting(&self) -> bool {
+        self.tx_cycles > 0
+    }
+
+    fn transmit(&mut self, val: u8) {
+        if self.is_transmitting() {
+            // the W65C51N does not report a busy transmitter, so firmware must wait out the
+            // byte time itself.  writing early clobbers the byte being shifted out.
+            warn!("ACIA: transmit of {:02x} while {:02x} still shifting out", val, self.tdr);
+        }
+
+        self.tdr = val & self.word_mask();
+        self.tx_cycles = self.byte_cycles();
+    }
+
+    fn receive(&mut self, val: u8) {
+        if self.status & STATUS_RDRF == STATUS_RDRF {
+            self.status |= STATUS_OVERRUN;
+        } else {
+            self.rdr = val & self.word_mask();
+            self.status |= STATUS_RDRF;
+
+            if self.command & COMMAND_IRD == 0 {
+                self.status |= STATUS_IRQ;
+            }
+        }
+
+        if self.command & COMMAND_REM == COMMAND_REM {
+            self.transmit(val);
+        }
+    }
+
+    pub fn cycle(&mut self) -> bool {
+        if self.tx_cycles > 0 {
+            self.tx_cycles -= 1;
+            if self.tx_cycles == 0 {
+                self.link.send(self.tdr);
+            }
+        }
+
+        if self.rx_cycles > 0 {
+            self.rx_cycles -= 1;
+            if self.rx_cycles == 0 {
+                self.receive(self.rsr);
+            }
+        } else if self.is_enabled() {
+            if let Some(val) = self.link.recv() {
+                // receiving a byte takes a full frame
+                self.rsr = val;
+                self.rx_cycles = self.byte_cycles();
+            }
+        }
+
+        self.is_enabled() && self.status & STATUS_IRQ == STATUS_IRQ
+    }
+
+    pub fn peek(&self, addr: u16) -> u8 {
+        match addr {
+            0x0 => self.rdr,
+            0x1 => self.status,
+            0x2 => self.command,
+            0x3 => self.control,
+            _ => panic!("attempt to access invalid W65C51 register: {}", addr),
+        }
+    }
+
+    pub fn read(&mut self, addr: u16) -> u8 {
+        let data = self.peek(addr);
+        match addr {
+            0x0 => {
+                self.status &= !(STATUS_RDRF | STATUS_OVERRUN);
+            }
+            0x1 => {
+                self.status &= !STATUS_IRQ;
+            }
+            _ => {}
+        }
+        debug!("R @ {:04x} = {:02x}", addr, data);
+        data
+    }
+
+    pub fn write(&mut self, addr: u16, data: u8) {
+        debug!("W @ {:04x} = {:02x}", addr, data);
+        match addr {
+            0x0 => {
+                self.transmit(data);
+            }
+            0x1 => {
+                // programmed reset
+                self.command &= 0xe0;
+                self.status &= !STATUS_OVERRUN;
+            }
+            0x2 => {
+                self.command = data;
+            }
+            0x3 => {
+                self.control = data;
+            }
+            _ => panic!("attempt to access invalid W65C51 register: {}", addr),
+        }
+    }
+}
@@ -0,0 +1,580 @@
+// HD44780U character generator ROMs.  each character has a Unicode stand-in for the terminal
+// and a 5x10 dot bitmap; each row holds its dots in the low five bits, leftmost dot in bit 4.
+// in 5x8 mode the top eight rows are shown, the eighth being shared with the cursor, except for
+// A00's characters E0-FF, which have their own 5x8 forms.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CharacterRom {
+    A00, // Japanese
+    A02, // European
+}
+
+impl CharacterRom {
+    pub fn parse(name: &str) -> Option<CharacterRom> {
+        match name.to_ascii_lowercase().as_str() {
+            "a00" => Some(CharacterRom::A00),
+            "a02" => Some(CharacterRom::A02),
+            _ => None,
+        }
+    }
+
+    pub fn get_char(self, code: u8) -> char {
+        match self {
+            CharacterRom::A00 => A00_CHARS[code as usize],
+            CharacterRom::A02 => A02_CHARS[code as usize],
+        }
+    }
+
+    pub fn get_glyph(self, code: u8) -> &'static [u8; 10] {
+        match self {
+            CharacterRom::A00 => &A00_GLYPHS[code as usize],
+            CharacterRom::A02 => &A02_GLYPHS[code as usize],
+        }
+    }
+
+    pub fn get_small_glyph(self, code: u8) -> [u8; 8] {
+        match self {
+            CharacterRom::A00 if code >= 0xE0 => A00_SMALL_GLYPHS[(code - 0xE0) as usize],
+            _ => {
+                let mut glyph = [0; 8];
+                glyph.copy_from_slice(&self.get_glyph(code)[..8]);
+                glyph
+            }
+        }
+    }
+}
+
+const BLANK: [u8; 10] = [0; 10];
+
+const A00_CHARS: [char; 256] = [
+    ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ',
+    ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ',
+    ' ', '!', '"', '#', '$', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/',
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?',
+    '@', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '[', '¥', ']', '^', '_',
+    '`', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o',
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '{', '|', '}', '→', '←',
+    ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ',
+    ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ',
+    ' ', '｡', '｢', '｣', '､', '･', 'ｦ', 'ｧ', 'ｨ', 'ｩ', 'ｪ', 'ｫ', 'ｬ', 'ｭ', 'ｮ', 'ｯ',
+    'ｰ', 'ｱ', 'ｲ', 'ｳ', 'ｴ', 'ｵ', 'ｶ', 'ｷ', 'ｸ', 'ｹ', 'ｺ', 'ｻ', 'ｼ', 'ｽ', 'ｾ', 'ｿ',
+    'ﾀ', 'ﾁ', 'ﾂ', 'ﾃ', 'ﾄ', 'ﾅ', 'ﾆ', 'ﾇ', 'ﾈ', 'ﾉ', 'ﾊ', 'ﾋ', 'ﾌ', 'ﾍ', 'ﾎ', 'ﾏ',
+    'ﾐ', 'ﾑ', 'ﾒ', 'ﾓ', 'ﾔ', 'ﾕ', 'ﾖ', 'ﾗ', 'ﾘ', 'ﾙ', 'ﾚ', 'ﾛ', 'ﾜ', 'ﾝ', 'ﾞ', 'ﾟ',
+    'α', 'ä', 'β', 'ε', 'μ', 'σ', 'ρ', 'g', '√', '¹', 'j', 'ˣ', '¢', '£', 'ñ', 'ö',
+    'p', 'q', 'θ', '∞', 'Ω', 'ü', 'Σ', 'π', 'x', 'y', '千', '万', '円', '÷', ' ', '█',
+];
+
+const A02_CHARS: [char; 256] = [
+    ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ',
+    '▶', '◀', '“', '”', '⇈', '⇊', '●', '↵', '↑', '↓', '→', '←', '≤', '≥', '▲', '▼',
+    ' ', '!', '"', '#', '$', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/',
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?',
+    '@', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '[', '\\', ']', '^', '_',
+    '`', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o',
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '{', '|', '}', '~', '⌂',
+    'Б', 'Д', 'Ж', 'З', 'И', 'Й', 'Л', 'П', 'У', 'Ц', 'Ч', 'Ш', 'Щ', 'Ъ', 'Ы', 'Э',
+    'α', '♪', 'Γ', 'π', 'Σ', 'σ', '♬', 'τ', '⍾', 'Θ', 'Ω', 'δ', '∞', '♥', 'ε', '∩',
+    ' ', '¡', '¢', '£', '¤', '¥', '¦', '§', 'ƒ', '©', 'ª', '«', 'Ю', 'Я', '®', '‘',
+    '°', '±', '²', '³', '₧', 'µ', '¶', '·', 'ω', '¹', 'º', '»', '¼', '½', '¾', '¿',
+    'À', 'Á', 'Â', 'Ã', 'Ä', 'Å', 'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï',
+    'Ð', 'Ñ', 'Ò', 'Ó', 'Ô', 'Õ', 'Ö', '×', 'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'Ý', 'Þ', 'ß',
+    'à', 'á', 'â', 'ã', 'ä', 'å', 'æ', 'ç', 'è', 'é', 'ê', 'ë', 'ì', 'í', 'î', 'ï',
+    'ð', 'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '÷', 'ø', 'ù', 'ú', 'û', 'ü', 'ý', 'þ', 'ÿ',
+];
+
+// ROM code A00: ASCII, with Japanese katakana and symbols in the upper half
+const A00_GLYPHS: [[u8; 10]; 256] = [
+    // 00-20
+    BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK,
+    BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK,
+    BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK,
+    BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK,
+    BLANK,
+    [0x04, 0x04, 0x04, 0x04, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00], // 21 !
+    [0x0A, 0x0A, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 22 "
+    [0x0A, 0x0A, 0x1F, 0x0A, 0x1F, 0x0A, 0x0A, 0x00, 0x00, 0x00], // 23 #
+    [0x04, 0x0F, 0x14, 0x0E, 0x05, 0x1E, 0x04, 0x00, 0x00, 0x00], // 24 $
+    [0x18, 0x19, 0x02, 0x04, 0x08, 0x13, 0x03, 0x00, 0x00, 0x00], // 25 %
+    [0x0C, 0x12, 0x14, 0x08, 0x15, 0x12, 0x0D, 0x00, 0x00, 0x00], // 26 &
+    [0x0C, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 27 '
+    [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02, 0x00, 0x00, 0x00], // 28 (
+    [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08, 0x00, 0x00, 0x00], // 29 )
+    [0x00, 0x04, 0x15, 0x0E, 0x15, 0x04, 0x00, 0x00, 0x00, 0x00], // 2A *
+    [0x00, 0x04, 0x04, 0x1F, 0x04, 0x04, 0x00, 0x00, 0x00, 0x00], // 2B +
+    [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08, 0x00, 0x00, 0x00], // 2C ,
+    [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 2D -
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00, 0x00, 0x00], // 2E .
+    [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00, 0x00, 0x00, 0x00], // 2F /
+    [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E, 0x00, 0x00, 0x00], // 30 0
+    [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // 31 1
+    [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F, 0x00, 0x00, 0x00], // 32 2
+    [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E, 0x00, 0x00, 0x00], // 33 3
+    [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02, 0x00, 0x00, 0x00], // 34 4
+    [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E, 0x00, 0x00, 0x00], // 35 5
+    [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // 36 6
+    [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00], // 37 7
+    [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // 38 8
+    [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C, 0x00, 0x00, 0x00], // 39 9
+    [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00, 0x00, 0x00, 0x00], // 3A :
+    [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x04, 0x08, 0x00, 0x00, 0x00], // 3B ;
+    [0x02, 0x04, 0x08, 0x10, 0x08, 0x04, 0x02, 0x00, 0x00, 0x00], // 3C <
+    [0x00, 0x00, 0x1F, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00], // 3D =
+    [0x08, 0x04, 0x02, 0x01, 0x02, 0x04, 0x08, 0x00, 0x00, 0x00], // 3E >
+    [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04, 0x00, 0x00, 0x00], // 3F ?
+    [0x0E, 0x11, 0x01, 0x0D, 0x15, 0x15, 0x0E, 0x00, 0x00, 0x00], // 40 @
+    [0x0E, 0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x00, 0x00, 0x00], // 41 A
+    [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E, 0x00, 0x00, 0x00], // 42 B
+    [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E, 0x00, 0x00, 0x00], // 43 C
+    [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C, 0x00, 0x00, 0x00], // 44 D
+    [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F, 0x00, 0x00, 0x00], // 45 E
+    [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10, 0x00, 0x00, 0x00], // 46 F
+    [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F, 0x00, 0x00, 0x00], // 47 G
+    [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // 48 H
+    [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // 49 I
+    [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C, 0x00, 0x00, 0x00], // 4A J
+    [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11, 0x00, 0x00, 0x00], // 4B K
+    [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F, 0x00, 0x00, 0x00], // 4C L
+    [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // 4D M
+    [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11, 0x00, 0x00, 0x00], // 4E N
+    [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // 4F O
+    [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10, 0x00, 0x00, 0x00], // 50 P
+    [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D, 0x00, 0x00, 0x00], // 51 Q
+    [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11, 0x00, 0x00, 0x00], // 52 R
+    [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E, 0x00, 0x00, 0x00], // 53 S
+    [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00], // 54 T
+    [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // 55 U
+    [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04, 0x00, 0x00, 0x00], // 56 V
+    [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A, 0x00, 0x00, 0x00], // 57 W
+    [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11, 0x00, 0x00, 0x00], // 58 X
+    [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00], // 59 Y
+    [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F, 0x00, 0x00, 0x00], // 5A Z
+    [0x0E, 0x08, 0x08, 0x08, 0x08, 0x08, 0x0E, 0x00, 0x00, 0x00], // 5B [
+    [0x11, 0x0A, 0x1F, 0x04, 0x1F, 0x04, 0x04, 0x00, 0x00, 0x00], // 5C ¥
+    [0x0E, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E, 0x00, 0x00, 0x00], // 5D ]
+    [0x04, 0x0A, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 5E ^
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00], // 5F _
+    [0x08, 0x04, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 60 `
+    [0x00, 0x00, 0x0E, 0x01, 0x0F, 0x11, 0x0F, 0x00, 0x00, 0x00], // 61 a
+    [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x1E, 0x00, 0x00, 0x00], // 62 b
+    [0x00, 0x00, 0x0E, 0x10, 0x10, 0x11, 0x0E, 0x00, 0x00, 0x00], // 63 c
+    [0x01, 0x01, 0x0D, 0x13, 0x11, 0x11, 0x0F, 0x00, 0x00, 0x00], // 64 d
+    [0x00, 0x00, 0x0E, 0x11, 0x1F, 0x10, 0x0E, 0x00, 0x00, 0x00], // 65 e
+    [0x06, 0x09, 0x08, 0x1C, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00], // 66 f
+    [0x00, 0x0F, 0x11, 0x11, 0x0F, 0x01, 0x0E, 0x00, 0x00, 0x00], // 67 g
+    [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // 68 h
+    [0x04, 0x00, 0x0C, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // 69 i
+    [0x02, 0x00, 0x06, 0x02, 0x02, 0x12, 0x0C, 0x00, 0x00, 0x00], // 6A j
+    [0x10, 0x10, 0x12, 0x14, 0x18, 0x14, 0x12, 0x00, 0x00, 0x00], // 6B k
+    [0x0C, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // 6C l
+    [0x00, 0x00, 0x1A, 0x15, 0x15, 0x11, 0x11, 0x00, 0x00, 0x00], // 6D m
+    [0x00, 0x00, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // 6E n
+    [0x00, 0x00, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // 6F o
+    [0x00, 0x00, 0x1E, 0x11, 0x1E, 0x10, 0x10, 0x00, 0x00, 0x00], // 70 p
+    [0x00, 0x00, 0x0D, 0x13, 0x0F, 0x01, 0x01, 0x00, 0x00, 0x00], // 71 q
+    [0x00, 0x00, 0x16, 0x19, 0x10, 0x10, 0x10, 0x00, 0x00, 0x00], // 72 r
+    [0x00, 0x00, 0x0E, 0x10, 0x0E, 0x01, 0x1E, 0x00, 0x00, 0x00], // 73 s
+    [0x08, 0x08, 0x1C, 0x08, 0x08, 0x09, 0x06, 0x00, 0x00, 0x00], // 74 t
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0D, 0x00, 0x00, 0x00], // 75 u
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x0A, 0x04, 0x00, 0x00, 0x00], // 76 v
+    [0x00, 0x00, 0x11, 0x11, 0x15, 0x15, 0x0A, 0x00, 0x00, 0x00], // 77 w
+    [0x00, 0x00, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x00, 0x00, 0x00], // 78 x
+    [0x00, 0x00, 0x11, 0x11, 0x0F, 0x01, 0x0E, 0x00, 0x00, 0x00], // 79 y
+    [0x00, 0x00, 0x1F, 0x02, 0x04, 0x08, 0x1F, 0x00, 0x00, 0x00], // 7A z
+    [0x02, 0x04, 0x04, 0x08, 0x04, 0x04, 0x02, 0x00, 0x00, 0x00], // 7B {
+    [0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00], // 7C |
+    [0x08, 0x04, 0x04, 0x02, 0x04, 0x04, 0x08, 0x00, 0x00, 0x00], // 7D }
+    [0x00, 0x04, 0x02, 0x1F, 0x02, 0x04, 0x00, 0x00, 0x00, 0x00], // 7E →
+    [0x00, 0x04, 0x08, 0x1F, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00], // 7F ←
+    // 80-A0
+    BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK,
+    BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK,
+    BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK,
+    BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK,
+    BLANK,
+    [0x00, 0x00, 0x00, 0x00, 0x1C, 0x14, 0x1C, 0x00, 0x00, 0x00], // A1 ｡
+    [0x07, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // A2 ｢
+    [0x00, 0x00, 0x00, 0x04, 0x04, 0x04, 0x1C, 0x00, 0x00, 0x00], // A3 ｣
+    [0x00, 0x00, 0x00, 0x00, 0x10, 0x08, 0x04, 0x00, 0x00, 0x00], // A4 ､
+    [0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00], // A5 ･
+    [0x00, 0x1F, 0x01, 0x1F, 0x01, 0x02, 0x04, 0x00, 0x00, 0x00], // A6 ｦ
+    [0x00, 0x00, 0x1F, 0x01, 0x06, 0x04, 0x08, 0x00, 0x00, 0x00], // A7 ｧ
+    [0x00, 0x00, 0x02, 0x04, 0x0C, 0x14, 0x04, 0x00, 0x00, 0x00], // A8 ｨ
+    [0x00, 0x00, 0x04, 0x1F, 0x11, 0x01, 0x06, 0x00, 0x00, 0x00], // A9 ｩ
+    [0x00, 0x00, 0x00, 0x1F, 0x04, 0x04, 0x1F, 0x00, 0x00, 0x00], // AA ｪ
+    [0x00, 0x00, 0x02, 0x1F, 0x06, 0x0A, 0x12, 0x00, 0x00, 0x00], // AB ｫ
+    [0x00, 0x00, 0x08, 0x1F, 0x09, 0x0A, 0x08, 0x00, 0x00, 0x00], // AC ｬ
+    [0x00, 0x00, 0x00, 0x0E, 0x02, 0x02, 0x1F, 0x00, 0x00, 0x00], // AD ｭ
+    [0x00, 0x00, 0x1E, 0x02, 0x1E, 0x02, 0x1E, 0x00, 0x00, 0x00], // AE ｮ
+    [0x00, 0x00, 0x00, 0x15, 0x15, 0x01, 0x06, 0x00, 0x00, 0x00], // AF ｯ
+    [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // B0 ｰ
+    [0x1F, 0x01, 0x05, 0x06, 0x04, 0x04, 0x08, 0x00, 0x00, 0x00], // B1 ｱ
+    [0x01, 0x02, 0x04, 0x0C, 0x14, 0x04, 0x04, 0x00, 0x00, 0x00], // B2 ｲ
+    [0x04, 0x1F, 0x11, 0x11, 0x01, 0x02, 0x04, 0x00, 0x00, 0x00], // B3 ｳ
+    [0x00, 0x1F, 0x04, 0x04, 0x04, 0x04, 0x1F, 0x00, 0x00, 0x00], // B4 ｴ
+    [0x02, 0x1F, 0x02, 0x06, 0x0A, 0x12, 0x02, 0x00, 0x00, 0x00], // B5 ｵ
+    [0x08, 0x1F, 0x09, 0x09, 0x09, 0x09, 0x12, 0x00, 0x00, 0x00], // B6 ｶ
+    [0x04, 0x1F, 0x04, 0x1F, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00], // B7 ｷ
+    [0x00, 0x0F, 0x09, 0x11, 0x01, 0x02, 0x0C, 0x00, 0x00, 0x00], // B8 ｸ
+    [0x08, 0x0F, 0x12, 0x02, 0x02, 0x02, 0x04, 0x00, 0x00, 0x00], // B9 ｹ
+    [0x00, 0x1F, 0x01, 0x01, 0x01, 0x01, 0x1F, 0x00, 0x00, 0x00], // BA ｺ
+    [0x0A, 0x1F, 0x0A, 0x0A, 0x02, 0x04, 0x08, 0x00, 0x00, 0x00], // BB ｻ
+    [0x00, 0x18, 0x01, 0x19, 0x01, 0x02, 0x1C, 0x00, 0x00, 0x00], // BC ｼ
+    [0x00, 0x1F, 0x01, 0x02, 0x04, 0x0A, 0x11, 0x00, 0x00, 0x00], // BD ｽ
+    [0x08, 0x1F, 0x09, 0x0A, 0x08, 0x08, 0x07, 0x00, 0x00, 0x00], // BE ｾ
+    [0x00, 0x11, 0x11, 0x09, 0x01, 0x02, 0x0C, 0x00, 0x00, 0x00], // BF ｿ
+    [0x00, 0x0F, 0x09, 0x15, 0x03, 0x02, 0x0C, 0x00, 0x00, 0x00], // C0 ﾀ
+    [0x02, 0x1C, 0x04, 0x1F, 0x04, 0x04, 0x08, 0x00, 0x00, 0x00], // C1 ﾁ
+    [0x00, 0x15, 0x15, 0x15, 0x01, 0x02, 0x04, 0x00, 0x00, 0x00], // C2 ﾂ
+    [0x0E, 0x00, 0x1F, 0x04, 0x04, 0x04, 0x08, 0x00, 0x00, 0x00], // C3 ﾃ
+    [0x08, 0x08, 0x08, 0x0C, 0x0A, 0x08, 0x08, 0x00, 0x00, 0x00], // C4 ﾄ
+    [0x04, 0x04, 0x1F, 0x04, 0x04, 0x08, 0x10, 0x00, 0x00, 0x00], // C5 ﾅ
+    [0x00, 0x0E, 0x00, 0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00], // C6 ﾆ
+    [0x00, 0x1F, 0x01, 0x0A, 0x04, 0x0A, 0x10, 0x00, 0x00, 0x00], // C7 ﾇ
+    [0x04, 0x1F, 0x02, 0x04, 0x0E, 0x15, 0x04, 0x00, 0x00, 0x00], // C8 ﾈ
+    [0x02, 0x02, 0x02, 0x02, 0x02, 0x04, 0x08, 0x00, 0x00, 0x00], // C9 ﾉ
+    [0x00, 0x04, 0x02, 0x11, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // CA ﾊ
+    [0x10, 0x10, 0x1F, 0x10, 0x10, 0x10, 0x0F, 0x00, 0x00, 0x00], // CB ﾋ
+    [0x00, 0x1F, 0x01, 0x01, 0x01, 0x02, 0x0C, 0x00, 0x00, 0x00], // CC ﾌ
+    [0x00, 0x08, 0x14, 0x02, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00], // CD ﾍ
+    [0x04, 0x1F, 0x04, 0x04, 0x15, 0x15, 0x04, 0x00, 0x00, 0x00], // CE ﾎ
+    [0x00, 0x1F, 0x01, 0x01, 0x0A, 0x04, 0x02, 0x00, 0x00, 0x00], // CF ﾏ
+    [0x00, 0x0E, 0x00, 0x0E, 0x00, 0x0E, 0x01, 0x00, 0x00, 0x00], // D0 ﾐ
+    [0x00, 0x04, 0x08, 0x10, 0x11, 0x1F, 0x01, 0x00, 0x00, 0x00], // D1 ﾑ
+    [0x00, 0x01, 0x01, 0x0A, 0x04, 0x0A, 0x10, 0x00, 0x00, 0x00], // D2 ﾒ
+    [0x00, 0x1F, 0x08, 0x1F, 0x08, 0x08, 0x07, 0x00, 0x00, 0x00], // D3 ﾓ
+    [0x08, 0x08, 0x1F, 0x09, 0x0A, 0x08, 0x08, 0x00, 0x00, 0x00], // D4 ﾔ
+    [0x00, 0x0E, 0x02, 0x02, 0x02, 0x02, 0x1F, 0x00, 0x00, 0x00], // D5 ﾕ
+    [0x00, 0x1F, 0x01, 0x1F, 0x01, 0x01, 0x1F, 0x00, 0x00, 0x00], // D6 ﾖ
+    [0x0E, 0x00, 0x1F, 0x01, 0x01, 0x02, 0x04, 0x00, 0x00, 0x00], // D7 ﾗ
+    [0x12, 0x12, 0x12, 0x12, 0x02, 0x04, 0x08, 0x00, 0x00, 0x00], // D8 ﾘ
+    [0x00, 0x04, 0x14, 0x14, 0x15, 0x15, 0x16, 0x00, 0x00, 0x00], // D9 ﾙ
+    [0x00, 0x10, 0x10, 0x11, 0x12, 0x14, 0x18, 0x00, 0x00, 0x00], // DA ﾚ
+    [0x00, 0x1F, 0x11, 0x11, 0x11, 0x11, 0x1F, 0x00, 0x00, 0x00], // DB ﾛ
+    [0x00, 0x1F, 0x11, 0x11, 0x01, 0x02, 0x04, 0x00, 0x00, 0x00], // DC ﾜ
+    [0x00, 0x18, 0x00, 0x01, 0x01, 0x02, 0x1C, 0x00, 0x00, 0x00], // DD ﾝ
+    [0x04, 0x12, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // DE ﾞ
+    [0x1C, 0x14, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // DF ﾟ
+    [0x00, 0x00, 0x09, 0x15, 0x12, 0x12, 0x0D, 0x00, 0x00, 0x00], // E0 α
+    [0x0A, 0x00, 0x0E, 0x01, 0x0F, 0x11, 0x0F, 0x00, 0x00, 0x00], // E1 ä
+    [0x00, 0x00, 0x0E, 0x11, 0x1E, 0x11, 0x1E, 0x10, 0x10, 0x00], // E2 β
+    [0x00, 0x00, 0x0E, 0x10, 0x0C, 0x11, 0x0E, 0x00, 0x00, 0x00], // E3 ε
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x13, 0x1D, 0x10, 0x10, 0x00], // E4 μ
+    [0x00, 0x00, 0x0F, 0x14, 0x12, 0x11, 0x0E, 0x00, 0x00, 0x00], // E5 σ
+    [0x00, 0x00, 0x06, 0x09, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x00], // E6 ρ
+    [0x00, 0x00, 0x0F, 0x11, 0x11, 0x11, 0x0F, 0x01, 0x01, 0x0E], // E7 g
+    [0x00, 0x00, 0x07, 0x04, 0x04, 0x14, 0x08, 0x00, 0x00, 0x00], // E8 √
+    [0x00, 0x02, 0x1A, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // E9 ¹
+    [0x02, 0x00, 0x06, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C, 0x00], // EA j
+    [0x00, 0x14, 0x08, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // EB ˣ
+    [0x00, 0x04, 0x0E, 0x14, 0x15, 0x0E, 0x04, 0x00, 0x00, 0x00], // EC ¢
+    [0x08, 0x08, 0x1C, 0x08, 0x1C, 0x08, 0x0F, 0x00, 0x00, 0x00], // ED £
+    [0x0E, 0x00, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // EE ñ
+    [0x0A, 0x00, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // EF ö
+    [0x00, 0x00, 0x16, 0x19, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x00], // F0 p
+    [0x00, 0x00, 0x0D, 0x13, 0x11, 0x11, 0x0F, 0x01, 0x01, 0x00], // F1 q
+    [0x00, 0x0E, 0x11, 0x1F, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // F2 θ
+    [0x00, 0x00, 0x00, 0x0B, 0x15, 0x1A, 0x00, 0x00, 0x00, 0x00], // F3 ∞
+    [0x00, 0x00, 0x0E, 0x11, 0x11, 0x0A, 0x1B, 0x00, 0x00, 0x00], // F4 Ω
+    [0x0A, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0D, 0x00, 0x00, 0x00], // F5 ü
+    [0x1F, 0x10, 0x08, 0x04, 0x08, 0x10, 0x1F, 0x00, 0x00, 0x00], // F6 Σ
+    [0x00, 0x00, 0x1F, 0x0A, 0x0A, 0x0A, 0x13, 0x00, 0x00, 0x00], // F7 π
+    [0x1F, 0x00, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x00, 0x00, 0x00], // F8 x
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x11, 0x0F, 0x01, 0x0E, 0x00], // F9 y
+    [0x00, 0x01, 0x1E, 0x04, 0x1F, 0x04, 0x04, 0x00, 0x00, 0x00], // FA 千
+    [0x00, 0x00, 0x1F, 0x08, 0x0F, 0x09, 0x11, 0x00, 0x00, 0x00], // FB 万
+    [0x00, 0x00, 0x1F, 0x15, 0x1F, 0x11, 0x11, 0x00, 0x00, 0x00], // FC 円
+    [0x00, 0x04, 0x00, 0x1F, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00], // FD ÷
+    // FE
+    BLANK,
+    [0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F], // FF █
+];
+
+// A00's 5x10 characters as shown in 5x8 mode, where the ones with descenders are raised a row so
+// that the descender fits
+const A00_SMALL_GLYPHS: [[u8; 8]; 32] = [
+    // E0-FF
+    [0x00, 0x00, 0x09, 0x15, 0x12, 0x12, 0x0D, 0x00], // E0 α
+    [0x0A, 0x00, 0x0E, 0x01, 0x0F, 0x11, 0x0F, 0x00], // E1 ä
+    [0x00, 0x0E, 0x11, 0x1E, 0x11, 0x1E, 0x10, 0x10], // E2 β
+    [0x00, 0x00, 0x0E, 0x10, 0x0C, 0x11, 0x0E, 0x00], // E3 ε
+    [0x00, 0x00, 0x11, 0x11, 0x13, 0x1D, 0x10, 0x10], // E4 μ
+    [0x00, 0x00, 0x0F, 0x14, 0x12, 0x11, 0x0E, 0x00], // E5 σ
+    [0x00, 0x00, 0x06, 0x09, 0x11, 0x1E, 0x10, 0x10], // E6 ρ
+    [0x00, 0x00, 0x0F, 0x11, 0x11, 0x0F, 0x01, 0x0E], // E7 g
+    [0x00, 0x00, 0x07, 0x04, 0x04, 0x14, 0x08, 0x00], // E8 √
+    [0x00, 0x02, 0x1A, 0x02, 0x00, 0x00, 0x00, 0x00], // E9 ¹
+    [0x02, 0x00, 0x06, 0x02, 0x02, 0x02, 0x12, 0x0C], // EA j
+    [0x00, 0x14, 0x08, 0x14, 0x00, 0x00, 0x00, 0x00], // EB ˣ
+    [0x00, 0x04, 0x0E, 0x14, 0x15, 0x0E, 0x04, 0x00], // EC ¢
+    [0x08, 0x08, 0x1C, 0x08, 0x1C, 0x08, 0x0F, 0x00], // ED £
+    [0x0E, 0x00, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00], // EE ñ
+    [0x0A, 0x00, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00], // EF ö
+    [0x00, 0x00, 0x16, 0x19, 0x11, 0x1E, 0x10, 0x10], // F0 p
+    [0x00, 0x00, 0x0D, 0x13, 0x11, 0x0F, 0x01, 0x01], // F1 q
+    [0x00, 0x0E, 0x11, 0x1F, 0x11, 0x11, 0x0E, 0x00], // F2 θ
+    [0x00, 0x00, 0x00, 0x0B, 0x15, 0x1A, 0x00, 0x00], // F3 ∞
+    [0x00, 0x00, 0x0E, 0x11, 0x11, 0x0A, 0x1B, 0x00], // F4 Ω
+    [0x0A, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0D, 0x00], // F5 ü
+    [0x1F, 0x10, 0x08, 0x04, 0x08, 0x10, 0x1F, 0x00], // F6 Σ
+    [0x00, 0x00, 0x1F, 0x0A, 0x0A, 0x0A, 0x13, 0x00], // F7 π
+    [0x1F, 0x00, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x00], // F8 x
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x0F, 0x01, 0x0E], // F9 y
+    [0x00, 0x01, 0x1E, 0x04, 0x1F, 0x04, 0x04, 0x00], // FA 千
+    [0x00, 0x00, 0x1F, 0x08, 0x0F, 0x09, 0x11, 0x00], // FB 万
+    [0x00, 0x00, 0x1F, 0x15, 0x1F, 0x11, 0x11, 0x00], // FC 円
+    [0x00, 0x04, 0x00, 0x1F, 0x00, 0x04, 0x00, 0x00], // FD ÷
+    // FE
+    [0x00; 8],
+    [0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F], // FF █
+];
+
+// ROM code A02: ASCII, with symbols, Cyrillic, Greek and Western European letters
+const A02_GLYPHS: [[u8; 10]; 256] = [
+    // 00-0F
+    BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK,
+    BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK, BLANK,
+    [0x08, 0x0C, 0x0E, 0x0F, 0x0E, 0x0C, 0x08, 0x00, 0x00, 0x00], // 10 ▶
+    [0x02, 0x06, 0x0E, 0x1E, 0x0E, 0x06, 0x02, 0x00, 0x00, 0x00], // 11 ◀
+    [0x09, 0x12, 0x1B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 12 “
+    [0x1B, 0x09, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 13 ”
+    [0x04, 0x0E, 0x1F, 0x00, 0x04, 0x0E, 0x1F, 0x00, 0x00, 0x00], // 14 ⇈
+    [0x1F, 0x0E, 0x04, 0x00, 0x1F, 0x0E, 0x04, 0x00, 0x00, 0x00], // 15 ⇊
+    [0x00, 0x0E, 0x1F, 0x1F, 0x1F, 0x0E, 0x00, 0x00, 0x00, 0x00], // 16 ●
+    [0x01, 0x01, 0x05, 0x09, 0x1F, 0x08, 0x04, 0x00, 0x00, 0x00], // 17 ↵
+    [0x04, 0x0E, 0x15, 0x04, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00], // 18 ↑
+    [0x04, 0x04, 0x04, 0x04, 0x15, 0x0E, 0x04, 0x00, 0x00, 0x00], // 19 ↓
+    [0x00, 0x04, 0x02, 0x1F, 0x02, 0x04, 0x00, 0x00, 0x00, 0x00], // 1A →
+    [0x00, 0x04, 0x08, 0x1F, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00], // 1B ←
+    [0x02, 0x04, 0x08, 0x04, 0x02, 0x00, 0x1F, 0x00, 0x00, 0x00], // 1C ≤
+    [0x08, 0x04, 0x02, 0x04, 0x08, 0x00, 0x1F, 0x00, 0x00, 0x00], // 1D ≥
+    [0x00, 0x04, 0x04, 0x0E, 0x0E, 0x1F, 0x00, 0x00, 0x00, 0x00], // 1E ▲
+    [0x00, 0x1F, 0x0E, 0x0E, 0x04, 0x04, 0x00, 0x00, 0x00, 0x00], // 1F ▼
+    // 20
+    BLANK,
+    [0x04, 0x04, 0x04, 0x04, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00], // 21 !
+    [0x0A, 0x0A, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 22 "
+    [0x0A, 0x0A, 0x1F, 0x0A, 0x1F, 0x0A, 0x0A, 0x00, 0x00, 0x00], // 23 #
+    [0x04, 0x0F, 0x14, 0x0E, 0x05, 0x1E, 0x04, 0x00, 0x00, 0x00], // 24 $
+    [0x18, 0x19, 0x02, 0x04, 0x08, 0x13, 0x03, 0x00, 0x00, 0x00], // 25 %
+    [0x0C, 0x12, 0x14, 0x08, 0x15, 0x12, 0x0D, 0x00, 0x00, 0x00], // 26 &
+    [0x0C, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 27 '
+    [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02, 0x00, 0x00, 0x00], // 28 (
+    [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08, 0x00, 0x00, 0x00], // 29 )
+    [0x00, 0x04, 0x15, 0x0E, 0x15, 0x04, 0x00, 0x00, 0x00, 0x00], // 2A *
+    [0x00, 0x04, 0x04, 0x1F, 0x04, 0x04, 0x00, 0x00, 0x00, 0x00], // 2B +
+    [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08, 0x00, 0x00, 0x00], // 2C ,
+    [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 2D -
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00, 0x00, 0x00], // 2E .
+    [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00, 0x00, 0x00, 0x00], // 2F /
+    [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E, 0x00, 0x00, 0x00], // 30 0
+    [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // 31 1
+    [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F, 0x00, 0x00, 0x00], // 32 2
+    [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E, 0x00, 0x00, 0x00], // 33 3
+    [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02, 0x00, 0x00, 0x00], // 34 4
+    [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E, 0x00, 0x00, 0x00], // 35 5
+    [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // 36 6
+    [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00], // 37 7
+    [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // 38 8
+    [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C, 0x00, 0x00, 0x00], // 39 9
+    [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00, 0x00, 0x00, 0x00], // 3A :
+    [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x04, 0x08, 0x00, 0x00, 0x00], // 3B ;
+    [0x02, 0x04, 0x08, 0x10, 0x08, 0x04, 0x02, 0x00, 0x00, 0x00], // 3C <
+    [0x00, 0x00, 0x1F, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00], // 3D =
+    [0x08, 0x04, 0x02, 0x01, 0x02, 0x04, 0x08, 0x00, 0x00, 0x00], // 3E >
+    [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04, 0x00, 0x00, 0x00], // 3F ?
+    [0x0E, 0x11, 0x01, 0x0D, 0x15, 0x15, 0x0E, 0x00, 0x00, 0x00], // 40 @
+    [0x0E, 0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x00, 0x00, 0x00], // 41 A
+    [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E, 0x00, 0x00, 0x00], // 42 B
+    [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E, 0x00, 0x00, 0x00], // 43 C
+    [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C, 0x00, 0x00, 0x00], // 44 D
+    [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F, 0x00, 0x00, 0x00], // 45 E
+    [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10, 0x00, 0x00, 0x00], // 46 F
+    [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F, 0x00, 0x00, 0x00], // 47 G
+    [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // 48 H
+    [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // 49 I
+    [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C, 0x00, 0x00, 0x00], // 4A J
+    [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11, 0x00, 0x00, 0x00], // 4B K
+    [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F, 0x00, 0x00, 0x00], // 4C L
+    [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // 4D M
+    [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11, 0x00, 0x00, 0x00], // 4E N
+    [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // 4F O
+    [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10, 0x00, 0x00, 0x00], // 50 P
+    [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D, 0x00, 0x00, 0x00], // 51 Q
+    [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11, 0x00, 0x00, 0x00], // 52 R
+    [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E, 0x00, 0x00, 0x00], // 53 S
+    [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00], // 54 T
+    [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // 55 U
+    [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04, 0x00, 0x00, 0x00], // 56 V
+    [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A, 0x00, 0x00, 0x00], // 57 W
+    [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11, 0x00, 0x00, 0x00], // 58 X
+    [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00], // 59 Y
+    [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F, 0x00, 0x00, 0x00], // 5A Z
+    [0x0E, 0x08, 0x08, 0x08, 0x08, 0x08, 0x0E, 0x00, 0x00, 0x00], // 5B [
+    [0x00, 0x10, 0x08, 0x04, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00], // 5C \
+    [0x0E, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E, 0x00, 0x00, 0x00], // 5D ]
+    [0x04, 0x0A, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 5E ^
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00], // 5F _
+    [0x08, 0x04, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 60 `
+    [0x00, 0x00, 0x0E, 0x01, 0x0F, 0x11, 0x0F, 0x00, 0x00, 0x00], // 61 a
+    [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x1E, 0x00, 0x00, 0x00], // 62 b
+    [0x00, 0x00, 0x0E, 0x10, 0x10, 0x11, 0x0E, 0x00, 0x00, 0x00], // 63 c
+    [0x01, 0x01, 0x0D, 0x13, 0x11, 0x11, 0x0F, 0x00, 0x00, 0x00], // 64 d
+    [0x00, 0x00, 0x0E, 0x11, 0x1F, 0x10, 0x0E, 0x00, 0x00, 0x00], // 65 e
+    [0x06, 0x09, 0x08, 0x1C, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00], // 66 f
+    [0x00, 0x0F, 0x11, 0x11, 0x0F, 0x01, 0x0E, 0x00, 0x00, 0x00], // 67 g
+    [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // 68 h
+    [0x04, 0x00, 0x0C, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // 69 i
+    [0x02, 0x00, 0x06, 0x02, 0x02, 0x12, 0x0C, 0x00, 0x00, 0x00], // 6A j
+    [0x10, 0x10, 0x12, 0x14, 0x18, 0x14, 0x12, 0x00, 0x00, 0x00], // 6B k
+    [0x0C, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // 6C l
+    [0x00, 0x00, 0x1A, 0x15, 0x15, 0x11, 0x11, 0x00, 0x00, 0x00], // 6D m
+    [0x00, 0x00, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // 6E n
+    [0x00, 0x00, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // 6F o
+    [0x00, 0x00, 0x1E, 0x11, 0x1E, 0x10, 0x10, 0x00, 0x00, 0x00], // 70 p
+    [0x00, 0x00, 0x0D, 0x13, 0x0F, 0x01, 0x01, 0x00, 0x00, 0x00], // 71 q
+    [0x00, 0x00, 0x16, 0x19, 0x10, 0x10, 0x10, 0x00, 0x00, 0x00], // 72 r
+    [0x00, 0x00, 0x0E, 0x10, 0x0E, 0x01, 0x1E, 0x00, 0x00, 0x00], // 73 s
+    [0x08, 0x08, 0x1C, 0x08, 0x08, 0x09, 0x06, 0x00, 0x00, 0x00], // 74 t
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0D, 0x00, 0x00, 0x00], // 75 u
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x0A, 0x04, 0x00, 0x00, 0x00], // 76 v
+    [0x00, 0x00, 0x11, 0x11, 0x15, 0x15, 0x0A, 0x00, 0x00, 0x00], // 77 w
+    [0x00, 0x00, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x00, 0x00, 0x00], // 78 x
+    [0x00, 0x00, 0x11, 0x11, 0x0F, 0x01, 0x0E, 0x00, 0x00, 0x00], // 79 y
+    [0x00, 0x00, 0x1F, 0x02, 0x04, 0x08, 0x1F, 0x00, 0x00, 0x00], // 7A z
+    [0x02, 0x04, 0x04, 0x08, 0x04, 0x04, 0x02, 0x00, 0x00, 0x00], // 7B {
+    [0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00], // 7C |
+    [0x08, 0x04, 0x04, 0x02, 0x04, 0x04, 0x08, 0x00, 0x00, 0x00], // 7D }
+    [0x00, 0x00, 0x00, 0x0D, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00], // 7E ~
+    [0x04, 0x0A, 0x11, 0x11, 0x11, 0x1F, 0x00, 0x00, 0x00, 0x00], // 7F ⌂
+    [0x1F, 0x10, 0x10, 0x1E, 0x11, 0x11, 0x1E, 0x00, 0x00, 0x00], // 80 Б
+    [0x0E, 0x0A, 0x0A, 0x0A, 0x0A, 0x1F, 0x11, 0x00, 0x00, 0x00], // 81 Д
+    [0x15, 0x15, 0x15, 0x0E, 0x15, 0x15, 0x15, 0x00, 0x00, 0x00], // 82 Ж
+    [0x1E, 0x01, 0x01, 0x06, 0x01, 0x01, 0x1E, 0x00, 0x00, 0x00], // 83 З
+    [0x11, 0x11, 0x13, 0x15, 0x19, 0x11, 0x11, 0x00, 0x00, 0x00], // 84 И
+    [0x0A, 0x04, 0x11, 0x13, 0x15, 0x19, 0x11, 0x00, 0x00, 0x00], // 85 Й
+    [0x07, 0x09, 0x09, 0x09, 0x09, 0x09, 0x11, 0x00, 0x00, 0x00], // 86 Л
+    [0x1F, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // 87 П
+    [0x11, 0x11, 0x11, 0x0F, 0x01, 0x11, 0x0E, 0x00, 0x00, 0x00], // 88 У
+    [0x12, 0x12, 0x12, 0x12, 0x12, 0x1F, 0x01, 0x00, 0x00, 0x00], // 89 Ц
+    [0x11, 0x11, 0x11, 0x0F, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00], // 8A Ч
+    [0x15, 0x15, 0x15, 0x15, 0x15, 0x15, 0x1F, 0x00, 0x00, 0x00], // 8B Ш
+    [0x15, 0x15, 0x15, 0x15, 0x15, 0x1F, 0x01, 0x00, 0x00, 0x00], // 8C Щ
+    [0x18, 0x08, 0x08, 0x0E, 0x09, 0x09, 0x0E, 0x00, 0x00, 0x00], // 8D Ъ
+    [0x11, 0x11, 0x11, 0x19, 0x15, 0x15, 0x19, 0x00, 0x00, 0x00], // 8E Ы
+    [0x0E, 0x11, 0x01, 0x07, 0x01, 0x11, 0x0E, 0x00, 0x00, 0x00], // 8F Э
+    [0x00, 0x00, 0x09, 0x15, 0x12, 0x12, 0x0D, 0x00, 0x00, 0x00], // 90 α
+    [0x04, 0x06, 0x05, 0x05, 0x04, 0x1C, 0x1C, 0x00, 0x00, 0x00], // 91 ♪
+    [0x1F, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x00, 0x00], // 92 Γ
+    [0x00, 0x00, 0x1F, 0x0A, 0x0A, 0x0A, 0x13, 0x00, 0x00, 0x00], // 93 π
+    [0x1F, 0x10, 0x08, 0x04, 0x08, 0x10, 0x1F, 0x00, 0x00, 0x00], // 94 Σ
+    [0x00, 0x00, 0x0F, 0x12, 0x12, 0x12, 0x0C, 0x00, 0x00, 0x00], // 95 σ
+    [0x06, 0x05, 0x07, 0x05, 0x05, 0x1B, 0x1B, 0x00, 0x00, 0x00], // 96 ♬
+    [0x00, 0x01, 0x0E, 0x14, 0x04, 0x04, 0x02, 0x00, 0x00, 0x00], // 97 τ
+    [0x04, 0x0E, 0x0E, 0x0E, 0x1F, 0x04, 0x00, 0x00, 0x00, 0x00], // 98 ⍾
+    [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // 99 Θ
+    [0x00, 0x0E, 0x11, 0x11, 0x11, 0x0A, 0x1B, 0x00, 0x00, 0x00], // 9A Ω
+    [0x06, 0x09, 0x04, 0x0A, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // 9B δ
+    [0x00, 0x00, 0x00, 0x0B, 0x15, 0x1A, 0x00, 0x00, 0x00, 0x00], // 9C ∞
+    [0x00, 0x0A, 0x1F, 0x1F, 0x0E, 0x04, 0x00, 0x00, 0x00, 0x00], // 9D ♥
+    [0x00, 0x00, 0x0E, 0x10, 0x0C, 0x11, 0x0E, 0x00, 0x00, 0x00], // 9E ε
+    [0x00, 0x0E, 0x11, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00, 0x00], // 9F ∩
+    // A0
+    BLANK,
+    [0x04, 0x00, 0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00], // A1 ¡
+    [0x04, 0x0E, 0x14, 0x14, 0x15, 0x0E, 0x04, 0x00, 0x00, 0x00], // A2 ¢
+    [0x06, 0x09, 0x08, 0x1E, 0x08, 0x09, 0x16, 0x00, 0x00, 0x00], // A3 £
+    [0x11, 0x0E, 0x0A, 0x0E, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00], // A4 ¤
+    [0x11, 0x0A, 0x1F, 0x04, 0x1F, 0x04, 0x04, 0x00, 0x00, 0x00], // A5 ¥
+    [0x04, 0x04, 0x04, 0x00, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00], // A6 ¦
+    [0x0E, 0x10, 0x0E, 0x11, 0x0E, 0x01, 0x0E, 0x00, 0x00, 0x00], // A7 §
+    [0x02, 0x05, 0x04, 0x0E, 0x04, 0x14, 0x08, 0x00, 0x00, 0x00], // A8 ƒ
+    [0x0E, 0x11, 0x17, 0x19, 0x17, 0x11, 0x0E, 0x00, 0x00, 0x00], // A9 ©
+    [0x0E, 0x01, 0x0F, 0x11, 0x0F, 0x00, 0x1F, 0x00, 0x00, 0x00], // AA ª
+    [0x00, 0x05, 0x0A, 0x14, 0x0A, 0x05, 0x00, 0x00, 0x00, 0x00], // AB «
+    [0x12, 0x15, 0x15, 0x1D, 0x15, 0x15, 0x12, 0x00, 0x00, 0x00], // AC Ю
+    [0x0F, 0x11, 0x11, 0x0F, 0x05, 0x09, 0x11, 0x00, 0x00, 0x00], // AD Я
+    [0x0E, 0x11, 0x1D, 0x1B, 0x1D, 0x1B, 0x0E, 0x00, 0x00, 0x00], // AE ®
+    [0x02, 0x04, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // AF ‘
+    [0x06, 0x09, 0x09, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // B0 °
+    [0x04, 0x04, 0x1F, 0x04, 0x04, 0x00, 0x1F, 0x00, 0x00, 0x00], // B1 ±
+    [0x0C, 0x02, 0x04, 0x0E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // B2 ²
+    [0x0C, 0x02, 0x04, 0x02, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00], // B3 ³
+    [0x1C, 0x14, 0x1C, 0x12, 0x17, 0x12, 0x13, 0x00, 0x00, 0x00], // B4 ₧
+    [0x00, 0x00, 0x11, 0x11, 0x13, 0x1D, 0x10, 0x00, 0x00, 0x00], // B5 µ
+    [0x0F, 0x1D, 0x1D, 0x0D, 0x05, 0x05, 0x05, 0x00, 0x00, 0x00], // B6 ¶
+    [0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // B7 ·
+    [0x00, 0x00, 0x00, 0x11, 0x15, 0x15, 0x0A, 0x00, 0x00, 0x00], // B8 ω
+    [0x04, 0x0C, 0x04, 0x0E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // B9 ¹
+    [0x0E, 0x11, 0x11, 0x0E, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x00], // BA º
+    [0x00, 0x14, 0x0A, 0x05, 0x0A, 0x14, 0x00, 0x00, 0x00, 0x00], // BB »
+    [0x10, 0x12, 0x14, 0x0B, 0x15, 0x07, 0x01, 0x00, 0x00, 0x00], // BC ¼
+    [0x10, 0x12, 0x14, 0x0E, 0x11, 0x02, 0x07, 0x00, 0x00, 0x00], // BD ½
+    [0x18, 0x09, 0x1A, 0x05, 0x0B, 0x17, 0x01, 0x00, 0x00, 0x00], // BE ¾
+    [0x04, 0x00, 0x04, 0x08, 0x10, 0x11, 0x0E, 0x00, 0x00, 0x00], // BF ¿
+    [0x08, 0x04, 0x0E, 0x11, 0x1F, 0x11, 0x11, 0x00, 0x00, 0x00], // C0 À
+    [0x02, 0x04, 0x0E, 0x11, 0x1F, 0x11, 0x11, 0x00, 0x00, 0x00], // C1 Á
+    [0x04, 0x0A, 0x0E, 0x11, 0x1F, 0x11, 0x11, 0x00, 0x00, 0x00], // C2 Â
+    [0x0D, 0x12, 0x0E, 0x11, 0x1F, 0x11, 0x11, 0x00, 0x00, 0x00], // C3 Ã
+    [0x0A, 0x00, 0x0E, 0x11, 0x1F, 0x11, 0x11, 0x00, 0x00, 0x00], // C4 Ä
+    [0x0E, 0x0A, 0x0E, 0x11, 0x1F, 0x11, 0x11, 0x00, 0x00, 0x00], // C5 Å
+    [0x0F, 0x14, 0x14, 0x1E, 0x14, 0x14, 0x17, 0x00, 0x00, 0x00], // C6 Æ
+    [0x0E, 0x11, 0x10, 0x10, 0x11, 0x0E, 0x04, 0x08, 0x00, 0x00], // C7 Ç
+    [0x08, 0x04, 0x1F, 0x10, 0x1E, 0x10, 0x1F, 0x00, 0x00, 0x00], // C8 È
+    [0x02, 0x04, 0x1F, 0x10, 0x1E, 0x10, 0x1F, 0x00, 0x00, 0x00], // C9 É
+    [0x04, 0x0A, 0x1F, 0x10, 0x1E, 0x10, 0x1F, 0x00, 0x00, 0x00], // CA Ê
+    [0x0A, 0x00, 0x1F, 0x10, 0x1E, 0x10, 0x1F, 0x00, 0x00, 0x00], // CB Ë
+    [0x08, 0x04, 0x0E, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // CC Ì
+    [0x02, 0x04, 0x0E, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // CD Í
+    [0x04, 0x0A, 0x0E, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // CE Î
+    [0x0A, 0x00, 0x0E, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // CF Ï
+    [0x0E, 0x09, 0x09, 0x1D, 0x09, 0x09, 0x0E, 0x00, 0x00, 0x00], // D0 Ð
+    [0x0D, 0x12, 0x11, 0x19, 0x15, 0x13, 0x11, 0x00, 0x00, 0x00], // D1 Ñ
+    [0x08, 0x04, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // D2 Ò
+    [0x02, 0x04, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // D3 Ó
+    [0x04, 0x0A, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // D4 Ô
+    [0x0D, 0x12, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // D5 Õ
+    [0x0A, 0x00, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // D6 Ö
+    [0x00, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x00, 0x00, 0x00, 0x00], // D7 ×
+    [0x0E, 0x13, 0x15, 0x15, 0x15, 0x19, 0x0E, 0x00, 0x00, 0x00], // D8 Ø
+    [0x08, 0x04, 0x11, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // D9 Ù
+    [0x02, 0x04, 0x11, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // DA Ú
+    [0x04, 0x0A, 0x11, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // DB Û
+    [0x0A, 0x00, 0x11, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // DC Ü
+    [0x02, 0x04, 0x11, 0x0A, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00], // DD Ý
+    [0x10, 0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x00, 0x00, 0x00], // DE Þ
+    [0x0C, 0x12, 0x12, 0x16, 0x11, 0x11, 0x16, 0x00, 0x00, 0x00], // DF ß
+    [0x08, 0x04, 0x0E, 0x01, 0x0F, 0x11, 0x0F, 0x00, 0x00, 0x00], // E0 à
+    [0x02, 0x04, 0x0E, 0x01, 0x0F, 0x11, 0x0F, 0x00, 0x00, 0x00], // E1 á
+    [0x04, 0x0A, 0x0E, 0x01, 0x0F, 0x11, 0x0F, 0x00, 0x00, 0x00], // E2 â
+    [0x0D, 0x12, 0x0E, 0x01, 0x0F, 0x11, 0x0F, 0x00, 0x00, 0x00], // E3 ã
+    [0x0A, 0x00, 0x0E, 0x01, 0x0F, 0x11, 0x0F, 0x00, 0x00, 0x00], // E4 ä
+    [0x0E, 0x0A, 0x0E, 0x01, 0x0F, 0x11, 0x0F, 0x00, 0x00, 0x00], // E5 å
+    [0x00, 0x00, 0x1A, 0x05, 0x0F, 0x14, 0x0F, 0x00, 0x00, 0x00], // E6 æ
+    [0x00, 0x00, 0x0E, 0x10, 0x11, 0x0E, 0x04, 0x08, 0x00, 0x00], // E7 ç
+    [0x08, 0x04, 0x0E, 0x11, 0x1F, 0x10, 0x0E, 0x00, 0x00, 0x00], // E8 è
+    [0x02, 0x04, 0x0E, 0x11, 0x1F, 0x10, 0x0E, 0x00, 0x00, 0x00], // E9 é
+    [0x04, 0x0A, 0x0E, 0x11, 0x1F, 0x10, 0x0E, 0x00, 0x00, 0x00], // EA ê
+    [0x0A, 0x00, 0x0E, 0x11, 0x1F, 0x10, 0x0E, 0x00, 0x00, 0x00], // EB ë
+    [0x08, 0x04, 0x0C, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // EC ì
+    [0x02, 0x04, 0x0C, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // ED í
+    [0x04, 0x0A, 0x0C, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // EE î
+    [0x0A, 0x00, 0x0C, 0x04, 0x04, 0x04, 0x0E, 0x00, 0x00, 0x00], // EF ï
+    [0x06, 0x0C, 0x02, 0x0F, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // F0 ð
+    [0x0D, 0x12, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // F1 ñ
+    [0x08, 0x04, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // F2 ò
+    [0x02, 0x04, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // F3 ó
+    [0x04, 0x0A, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // F4 ô
+    [0x0D, 0x12, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // F5 õ
+    [0x0A, 0x00, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, 0x00, 0x00], // F6 ö
+    [0x00, 0x04, 0x00, 0x1F, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00], // F7 ÷
+    [0x00, 0x00, 0x0E, 0x13, 0x15, 0x19, 0x0E, 0x00, 0x00, 0x00], // F8 ø
+    [0x08, 0x04, 0x11, 0x11, 0x11, 0x13, 0x0D, 0x00, 0x00, 0x00], // F9 ù
+    [0x02, 0x04, 0x11, 0x11, 0x11, 0x13, 0x0D, 0x00, 0x00, 0x00], // FA ú
+    [0x04, 0x0A, 0x11, 0x11, 0x11, 0x13, 0x0D, 0x00, 0x00, 0x00], // FB û
+    [0x0A, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0D, 0x00, 0x00, 0x00], // FC ü
+    [0x02, 0x04, 0x11, 0x11, 0x0F, 0x01, 0x0E, 0x00, 0x00, 0x00], // FD ý
+    [0x10, 0x10, 0x1E, 0x11, 0x1E, 0x10, 0x10, 0x00, 0x00, 0x00], // FE þ
+    [0x0A, 0x00, 0x11, 0x11, 0x0F, 0x01, 0x0E, 0x00, 0x00, 0x00], // FF ÿ
+];
@@ -0,0 +1,618 @@
+use log::debug;
+use std::fmt;
+
+use crate::components::periph::{ControlLine, Port, Ports};
+
+#[derive(Debug)]
+enum Interrupts {
+    TimerA = 0x01,
+    TimerB = 0x02,
+    Alarm = 0x04,
+    Serial = 0x08,
+    Flag = 0x10,
+}
+
+// control register bits shared by CRA and CRB
+const START: u8 = 0x01;
+const PBON: u8 = 0x02;
+const OUTMODE: u8 = 0x04;
+const RUNMODE: u8 = 0x08;
+const LOAD: u8 = 0x10;
+
+// control register bits specific to CRA
+const CRA_INMODE: u8 = 0x20;
+const CRA_SPMODE: u8 = 0x40;
+const CRA_TODIN: u8 = 0x80;
+
+// control register bits specific to CRB
+const CRB_ALARM: u8 = 0x80;
+
+#[derive(Debug)]
+pub struct IntervalTimer {
+    pub counter: u16,
+    pub latch: u16,
+    pub output: bool,
+}
+
+impl IntervalTimer {
+    fn new() -> IntervalTimer {
+        IntervalTimer {
+            counter: 0xffff,
+            latch: 0xffff,
+            output: false,
+        }
+    }
+
+    // count down once, returning true on underflow
+    fn count(&mut self, control: &mut u8) -> bool {
+        if self.counter > 0 {
+            self.counter -= 1;
+            return false;
+        }
+
+        self.counter = self.latch;
+
+        if *control & RUNMODE == RUNMODE {
+            // one-shot mode stops the timer on underflow
+            *control &= !START;
+        }
+
+        if *control & OUTMODE == OUTMODE {
+            // toggle mode
+            self.output = !self.output;
+        } else {
+            // pulse mode, output is high for a single cycle
+            self.output = true;
+        }
+
+        true
+    }
+}
+
+pub struct MOS6526<PortsType: Ports> {
+    pub pra: u8,
+    pub prb: u8,
+    pub ddra: u8,
+    pub ddrb: u8,
+    pub ta: IntervalTimer,
+    pub tb: IntervalTimer,
+    pub tod: [u8; 4], // tenths, seconds, minutes, hours (BCD, bit 7 of hours is PM)
+    pub tod_alarm: [u8; 4],
+    pub tod_latch: Option<[u8; 4]>,
+    pub tod_running: bool,
+    pub tod_period: u32, // cycles per pulse on the TOD pin
+    pub tod_cycles: u32,
+    pub tod_pulses: u8,
+    pub sdr: u8,
+    pub sr: u8,
+    pub sr_bits: u8,
+    pub sr_pending: bool,
+    pub icr: u8,
+    pub imr: u8,
+    pub cra: u8,
+    pub crb: u8,
+    pub cnt: bool,
+    pub flag: bool,
+    pub ports: PortsType,
+}
+
+impl<PortsType: Ports> fmt::Debug for MOS6526<PortsType> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MOS6526")
+            .field("pra", &self.pra)
+            .field("prb", &self.prb)
+            .field("ddra", &self.ddra)
+            .field("ddrb", &self.ddrb)
+            .field("ta", &self.ta)
+            .field("tb", &self.tb)
+            .field("tod", &self.tod)
+            .field("icr", &self.icr)
+            .field("imr", &self.imr)
+            .field("cra", &self.cra)
+            .field("crb", &self.crb)
+            .finish()
+    }
+}
+
+impl<PortsType: Ports> MOS6526<PortsType> {
+    pub fn new(ports: PortsType, tod_period: u32) -> MOS6526<PortsType> {
+        MOS6526 {
+            pra: 0,
+            prb: 0,
+            ddra: 0,
+            ddrb: 0,
+            ta: IntervalTimer::new(),
+            tb: IntervalTimer::new(),
+            tod: [0x00, 0x00, 0x00, 0x01],
+            tod_alarm: [0x00, 0x00, 0x00, 0x00],
+            tod_latch: None,
+            tod_running: false,
+            tod_period,
+            tod_cycles: 0,
+            tod_pulses: 0,
+            sdr: 0,
+            sr: 0,
+            sr_bits: 0,
+            sr_pending: false,
+            icr: 0,
+            imr: 0,
+            cra: 0,
+            crb: 0,
+            cnt: true,
+            flag: true,
+            ports,
+        }
+    }
+
+    fn set_interrupt(&mut self, i: Interrupts) {
+        debug!("Set interrupt: {:?}", i);
+        self.icr |= i as u8;
+    }
+
+    fn port_a_output(&self) -> u8 {
+        // input pins are pulled up
+        (self.pra & self.ddra) | !self.ddra
+    }
+
+    fn port_b_output(&self) -> u8 {
+        let mut result = (self.prb & self.ddrb) | !self.ddrb;
+
+        // timer outputs override PB6 and PB7 regardless of DDRB
+        if self.cra & PBON == PBON {
+            result = (result & !0x40) | ((self.ta.output as u8) << 6);
+        }
+        if self.crb & PBON == PBON {
+            result = (result & !0x80) | ((self.tb.output as u8) << 7);
+        }
+
+        result
+    }
+
+    fn port_b_input(&self, pins: u8) -> u8 {
+        let mut result = (self.prb & self.ddrb) | (pins & !self.ddrb);
+
+        if self.cra & PBON == PBON {
+            result = (result & !0x40) | ((self.ta.output as u8) << 6);
+        }
+        if self.crb & PBON == PBON {
+            result = (result & !0x80) | ((self.tb.output as u8) << 7);
+        }
+
+        result
+    }
+
+    fn tod_tick(&mut self) {
+        fn bcd_increment(val: u8) -> u8 {
+            if val & 0x0f == 0x09 {
+                (val & 0xf0) + 0x10
+            } else {
+                val + 1
+            }
+        }
+
+        if self.tod[0] < 0x09 {
+            self.tod[0] += 1;
+        } else {
+            self.tod[0] = 0;
+
+            if self.tod[1] < 0x59 {
+                self.tod[1] = bcd_increment(self.tod[1]);
+            } else {
+                self.tod[1] = 0;
+
+                if self.tod[2] < 0x59 {
+                    self.tod[2] = bcd_increment(self.tod[2]);
+                } else {
+                    self.tod[2] = 0;
+
+                    let pm = self.tod[3] & 0x80;
+                    self.tod[3] = match self.tod[3] & 0x1f {
+                        0x11 => 0x12 | (pm ^ 0x80),
+                        0x12 => 0x01 | pm,
+                        h => bcd_increment(h) | pm,
+                    };
+                }
+            }
+        }
+
+        self.check_alarm();
+    }
+
+    // the comparator runs all the time, so setting the clock or the alarm to match also fires it
+    fn check_alarm(&mut self) {
+        if self.tod == self.tod_alarm {
+            self.set_interrupt(Interrupts::Alarm);
+        }
+    }
+
+    fn cycle_tod(&mut self) {
+        self.tod_cycles += 1;
+        if self.tod_cycles < self.tod_period {
+            return;
+        }
+        self.tod_cycles = 0;
+
+        // the TOD pin is fed 50Hz or 60Hz, which is divided down to tenths of a second
+        let divider = if self.cra & CRA_TODIN == CRA_TODIN { 5 } else { 6 };
+        self.tod_pulses += 1;
+        if self.tod_pulses >= divider {
+            self.tod_pulses = 0;
+            if self.tod_running {
+                self.tod_tick();
+            }
+        }
+    }
+
+    fn shift_out(&mut self) {
+        // called on each timer A underflow, one bit takes two underflows
+        if self.sr_bits == 0 {
+            if !self.sr_pending {
+                return;
+            }
+            self.sr = self.sdr;
+            self.sr_bits = 16;
+            self.sr_pending = false;
+        }
+
+        self.sr_bits -= 1;
+
+        if self.sr_bits % 2 == 1 {
+            self.cnt = false;
+            self.ports.write_control(ControlLine::Sp, self.sr & 0x80 == 0x80);
+            self.ports.write_control(ControlLine::Cnt, false);
+        } else {
+            self.cnt = true;
+            self.sr <<= 1;
+            self.ports.write_control(ControlLine::Cnt, true);
+
+            if self.sr_bits == 0 {
+                self.set_interrupt(Interrupts::Serial);
+            }
+        }
+    }
+
+    fn shift_in(&mut self) {
+        // called on each rising edge of CNT
+        let bit = self.ports.peek_control(ControlLine::Sp) as u8;
+        self.sr = (self.sr << 1) | bit;
+        self.sr_bits += 1;
+
+        if self.sr_bits == 8 {
+            self.sr_bits = 0;
+            self.sdr = self.sr;
+            self.set_interrupt(Interrupts::Serial);
+        }
+    }
+
+    pub fn cycle(&mut self) -> bool {
+        let outputs = (self.ta.output, self.tb.output);
+
+        // pulse mode outputs only last a single cycle
+        if self.cra & OUTMODE == 0 {
+            self.ta.output = false;
+        }
+        if self.crb & OUTMODE == 0 {
+            self.tb.output = false;
+        }
+
+        // CNT is an input unless the serial port is in output mode
+        let mut cnt_rise = false;
+        if self.cra & CRA_SPMODE == 0 {
+            let cnt = self.ports.peek_control(ControlLine::Cnt);
+            cnt_rise = cnt && !self.cnt;
+            self.cnt = cnt;
+
+            if cnt_rise {
+                self.shift_in();
+            }
+        }
+
+        // FLAG is active on the negative edge
+        let flag = self.ports.peek_control(ControlLine::Flag);
+        if self.flag && !flag {
+            self.set_interrupt(Interrupts::Flag);
+        }
+        self.flag = flag;
+
+        let mut ta_underflow = false;
+        if self.cra & START == START && (self.cra & CRA_INMODE == 0 || cnt_rise) {
+            ta_underflow = self.ta.count(&mut self.cra);
+        }
+
+        if ta_underflow {
+            self.set_interrupt(Interrupts::TimerA);
+            if self.cra & CRA_SPMODE == CRA_SPMODE {
+                self.shift_out();
+            }
+        }
+
+        if self.crb & START == START {
+            let count = match (self.crb >> 5) & 0x03 {
+                0 => true,
+                1 => cnt_rise,
+                2 => ta_underflow,
+                3 => ta_underflow && self.cnt,
+                _ => panic!("impossible value for self.crb"),
+            };
+
+            if count && self.tb.count(&mut self.crb) {
+                self.set_interrupt(Interrupts::TimerB);
+            }
+        }
+
+        self.cycle_tod();
+
+        if (self.cra & PBON == PBON || self.crb & PBON == PBON)
+            && outputs != (self.ta.output, self.tb.output)
+        {
+            let prb = self.port_b_output();
+            self.ports.write(Port::B, prb);
+        }
+
+        (self.icr & self.imr) != 0
+    }
+
+    pub fn peek(&self, addr: u16) -> u8 {
+        match addr {
+            0x0 => (self.pra & self.ddra) | (self.ports.peek(Port::A) & !self.ddra),
+            0x1 => self.port_b_input(self.ports.peek(Port::B)),
+            0x2 => self.ddra,
+            0x3 => self.ddrb,
+            0x4 => (self.ta.counter & 0x00ff) as u8,
+            0x5 => (self.ta.counter >> 8) as u8,
+            0x6 => (self.tb.counter & 0x00ff) as u8,
+            0x7 => (self.tb.counter >> 8) as u8,
+            0x8..=0xB => self.tod_latch.unwrap_or(self.tod)[(addr - 0x8) as usize],
+            0xC => self.sdr,
+            0xD => {
+                if self.icr & self.imr != 0 {
+                    self.icr | 0x80
+                } else {
+                    self.icr
+                }
+            }
+            0xE => self.cra,
+            0xF => self.crb,
+            _ => panic!("attempt to access invalid MOS6526 register: {}", addr),
+        }
+    }
+
+    pub fn read(&mut self, addr: u16) -> u8 {
+        let data = match addr {
+            0x0 => (self.pra & self.ddra) | (self.ports.read(Port::A) & !self.ddra),
+            0x1 => {
+                let pins = self.ports.read(Port::B);
+                self.port_b_input(pins)
+            }
+            0x8 => {
+                // reading tenths releases the latch
+                let result = self.tod_latch.unwrap_or(self.tod)[0];
+                self.tod_latch = None;
+                result
+            }
+            0xB => {
+                // reading hours latches the clock until tenths are read
+                if self.tod_latch.is_none() {
+                    self.tod_latch = Some(self.tod);
+                }
+                self.tod_latch.unwrap()[3]
+            }
+            0xD => {
+                // reading the ICR clears all interrupts
+                let result = self.peek(addr);
+                self.icr = 0;
+                result
+            }
+            _ => self.peek(addr),
+        };
+        debug!("R @ {:04x} = {:02x}", addr, data);
+        data
+    }
+
+    pub fn write(&mut self, addr: u16, data: u8) {
+        debug!("W @ {:04x} = {:02x}", addr, data);
+        match addr {
+            0x0 => {
+                self.pra = data;
+                self.ports.write(Port::A, self.port_a_output());
+            }
+            0x1 => {
+                self.prb = data;
+                self.ports.write(Port::B, self.port_b_output());
+            }
+            0x2 => {
+                self.ddra = data;
+                self.ports.write_direction(Port::A, data);
+                self.ports.write(Port::A, self.port_a_output());
+            }
+            0x3 => {
+                self.ddrb = data;
+                self.ports.write_direction(Port::B, data);
+                self.ports.write(Port::B, self.port_b_output());
+            }
+            0x4 => {
+                self.ta.latch = (self.ta.latch & 0xff00) | (data as u16);
+            }
+            0x5 => {
+                self.ta.latch = (self.ta.latch & 0x00ff) | ((data as u16) << 8);
+                if self.cra & START == 0 {
+                    self.ta.counter = self.ta.latch;
+                    if self.cra & RUNMODE == RUNMODE {
+                        self.cra |= START;
+                    }
+                }
+            }
+            0x6 => {
+                self.tb.latch = (self.tb.latch & 0xff00) | (data as u16);
+            }
+            0x7 => {
+                self.tb.latch = (self.tb.latch & 0x00ff) | ((data as u16) << 8);
+                if self.crb & START == 0 {
+                    self.tb.counter = self.tb.latch;
+                    if self.crb & RUNMODE == RUNMODE {
+                        self.crb |= START;
+                    }
+                }
+            }
+            0x8..=0xB => {
+                let ix = (addr - 0x8) as usize;
+                let val = match ix {
+                    0 => data & 0x0f,
+                    1 | 2 => data & 0x7f,
+                    _ => data & 0x9f,
+                };
+
+                if self.crb & CRB_ALARM == CRB_ALARM {
+                    self.tod_alarm[ix] = val;
+                } else {
+                    self.tod[ix] = val;
+                    match ix {
+                        // writing hours stops the clock until tenths are written
+                        3 => self.tod_running = false,
+                        0 => self.tod_running = true,
+                        _ => {}
+                    }
+                }
+                self.check_alarm();
+            }
+            0xC => {
+                self.sdr = data;
+                if self.cra & CRA_SPMODE == CRA_SPMODE {
+                    self.sr_pending = true;
+                }
+            }
+            0xD => {
+                if data & 0x80 == 0x80 {
+                    self.imr |= data & 0x1f;
+                } else {
+                    self.imr &= !(data & 0x1f);
+                }
+            }
+            0xE => {
+                if (self.cra ^ data) & CRA_SPMODE == CRA_SPMODE {
+                    // changing serial direction abandons any transfer in progress
+                    self.sr_bits = 0;
+                    self.sr_pending = false;
+                    if data & CRA_SPMODE == 0 {
+                        // an input lets go of SP and CNT
+                        self.cnt = true;
+                        self.ports.write_control(ControlLine::Sp, true);
+                        self.ports.write_control(ControlLine::Cnt, true);
+                    }
+                }
+                self.cra = data & !LOAD;
+                if data & LOAD == LOAD {
+                    self.ta.counter = self.ta.latch;
+                }
+                self.ports.write(Port::B, self.port_b_output());
+            }
+            0xF => {
+                self.crb = data & !LOAD;
+                if data & LOAD == LOAD {
+                    self.tb.counter = self.tb.latch;
+                }
+                self.ports.write(Port::B, self.port_b_output());
+            }
+            _ => panic!("attempt to access invalid MOS6526 register: {}", addr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // pins that read high, and the serial port lines as last written
+    #[derive(Default)]
+    struct TestPorts {
+        sp: Vec<bool>,
+        cnt: Vec<bool>,
+    }
+
+    impl Ports for TestPorts {
+        fn peek(&self, _port: Port) -> u8 {
+            0xff
+        }
+
+        fn read(&mut self, port: Port) -> u8 {
+            self.peek(port)
+        }
+
+        fn write(&mut self, _port: Port, _val: u8) {}
+
+        fn peek_control(&self, _line: ControlLine) -> bool {
+            true
+        }
+
+        fn write_control(&mut self, line: ControlLine, val: bool) {
+            match line {
+                ControlLine::Sp => self.sp.push(val),
+                ControlLine::Cnt => self.cnt.push(val),
+                _ => {}
+            }
+        }
+    }
+
+    fn set_clock(cia: &mut MOS6526<TestPorts>, alarm: bool, time: [u8; 4]) {
+        cia.write(0xF, if alarm { CRB_ALARM } else { 0 });
+        for ix in (0..4).rev() {
+            cia.write(0x8 + ix as u16, time[ix]);
+        }
+    }
+
+    #[test]
+    fn alarm_fires_when_the_clock_reaches_it() {
+        let mut cia = MOS6526::new(TestPorts::default(), 1);
+        set_clock(&mut cia, false, [0x00, 0x00, 0x00, 0x01]);
+        set_clock(&mut cia, true, [0x01, 0x00, 0x00, 0x01]);
+        cia.read(0xD);
+
+        // six pulses on the TOD pin make a tenth
+        for _ in 0..5 {
+            cia.cycle();
+        }
+        assert_eq!(cia.icr & Interrupts::Alarm as u8, 0);
+        cia.cycle();
+        assert_eq!(cia.icr & Interrupts::Alarm as u8, Interrupts::Alarm as u8);
+    }
+
+    #[test]
+    fn alarm_fires_when_the_clock_is_set_to_it() {
+        let mut cia = MOS6526::new(TestPorts::default(), 1000);
+        set_clock(&mut cia, true, [0x05, 0x30, 0x12, 0x03]);
+        cia.read(0xD);
+
+        set_clock(&mut cia, false, [0x05, 0x30, 0x12, 0x03]);
+        assert_eq!(cia.read(0xD) & Interrupts::Alarm as u8, Interrupts::Alarm as u8);
+    }
+
+    #[test]
+    fn alarm_fires_when_set_to_the_clock() {
+        let mut cia = MOS6526::new(TestPorts::default(), 1000);
+        set_clock(&mut cia, false, [0x00, 0x15, 0x00, 0x02]);
+        cia.read(0xD);
+
+        set_clock(&mut cia, true, [0x00, 0x15, 0x00, 0x02]);
+        assert_eq!(cia.read(0xD) & Interrupts::Alarm as u8, Interrupts::Alarm as u8);
+    }
+
+    #[test]
+    fn serial_output_shifts_msb_first_on_cnt() {
+        let mut cia = MOS6526::new(TestPorts::default(), 1000);
+        cia.write(0x4, 0x00);
+        cia.write(0x5, 0x00);
+        cia.write(0xE, START | CRA_SPMODE | LOAD);
+        cia.write(0xC, 0xA5);
+
+        for _ in 0..32 {
+            cia.cycle();
+        }
+
+        // CNT falls with each bit on SP, and rises again once it has been held
+        assert_eq!(cia.ports.sp, [true, false, true, false, false, true, false, true]);
+        assert_eq!(cia.ports.cnt.len(), 16);
+        assert!(cia.ports.cnt.iter().step_by(2).all(|cnt| !cnt));
+        assert!(cia.ports.cnt.iter().skip(1).step_by(2).all(|cnt| *cnt));
+        assert_eq!(cia.icr & Interrupts::Serial as u8, Interrupts::Serial as u8);
+    }
+}
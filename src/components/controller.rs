@@ -1,53 +1,180 @@
 use log::debug;
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+// SNES bit positions, in the order the pad clocks them out
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Button {
-    A = 0,
-    B = 1,
+    B = 0,
+    Y = 1,
     Select = 2,
     Start = 3,
     Up = 4,
     Down = 5,
     Left = 6,
     Right = 7,
+    A = 8,
+    X = 9,
+    L = 10,
+    R = 11,
 }
 
+const BUTTON_NAMES: [(&str, Button); 12] = [
+    ("up", Button::Up),
+    ("down", Button::Down),
+    ("left", Button::Left),
+    ("right", Button::Right),
+    ("a", Button::A),
+    ("b", Button::B),
+    ("x", Button::X),
+    ("y", Button::Y),
+    ("l", Button::L),
+    ("r", Button::R),
+    ("select", Button::Select),
+    ("start", Button::Start),
+];
+
+impl Button {
+    pub fn parse(name: &str) -> Option<Button> {
+        BUTTON_NAMES.iter().find(|(n, _)| *n == name).map(|(_, btn)| *btn)
+    }
+
+    pub fn name(self) -> &'static str {
+        BUTTON_NAMES.iter().find(|(_, btn)| *btn == self).unwrap().0
+    }
+}
+
+pub trait Controller {
+    fn on_press(&mut self, btn: Button);
+    fn on_release(&mut self, btn: Button);
+    // data line, in bit 0
+    fn peek(&self) -> u8;
+    fn write(&mut self, latch: bool, clk: bool);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ControllerType {
+    Snes,
+    Nes,
+}
+
+impl ControllerType {
+    pub fn parse(name: &str) -> Option<ControllerType> {
+        match name {
+            "snes" => Some(ControllerType::Snes),
+            "nes" => Some(ControllerType::Nes),
+            _ => None,
+        }
+    }
+
+    pub fn create(self) -> Box<dyn Controller> {
+        match self {
+            ControllerType::Snes => Box::new(SNESController::new()),
+            ControllerType::Nes => Box::new(NESController::new()),
+        }
+    }
+}
+
+// bits 12-15 identify a standard pad and, like released buttons, read as 1
+const RELEASED: u16 = 0xFFFF;
+
 #[derive(Debug)]
 pub struct SNESController {
-    pub state: u8, // current, ephemeral button states
-    pub latch: u8, // latched button states.  button presses immediately update the latch.
-    pub shift: u8, // shift register value.  transfers from latch on latch signal.
+    pub state: u16, // current, ephemeral button states
+    pub latch: u16, // latched button states.  button presses immediately update the latch.
+    pub shift: u16, // shift register value.  transfers from latch on latch signal.
 }
 
 impl SNESController {
     pub fn new() -> SNESController {
         SNESController {
+            state: RELEASED,
+            latch: RELEASED,
+            shift: RELEASED,
+        }
+    }
+}
+
+impl Controller for SNESController {
+    fn on_press(&mut self, btn: Button) {
+        let bit = btn as u16;
+        self.state &= !(1u16 << bit);
+        self.latch &= !(1u16 << bit);
+    }
+
+    fn on_release(&mut self, btn: Button) {
+        self.state |= 1u16 << (btn as u16);
+    }
+
+    fn peek(&self) -> u8 {
+        (self.shift & 1) as u8
+    }
+
+    fn write(&mut self, latch: bool, clk: bool) {
+        debug!("W L={:?} C={:?}", latch, clk);
+
+        if latch {
+            self.shift = self.latch;
+            self.latch = self.state;
+        }
+
+        // the serial input is tied high, so reads after the 16th bit return 1
+        if clk {
+            self.shift = (self.shift >> 1) | 0x8000;
+        }
+    }
+}
+
+// an NES pad: a single 4021 shift register holding eight buttons
+#[derive(Debug)]
+pub struct NESController {
+    pub state: u8, // current, ephemeral button states
+    pub latch: u8, // latched button states.  button presses immediately update the latch.
+    pub shift: u8, // shift register value.  transfers from latch on latch signal.
+}
+
+impl NESController {
+    pub fn new() -> NESController {
+        NESController {
             state: 255u8,
             latch: 255u8,
             shift: 255u8,
         }
     }
 
-    pub fn on_press(&mut self, btn: Button) {
-        let bit = btn as u8;
-        self.state &= !(1u8 << bit);
-        self.latch &= !(1u8 << bit);
+    // bit positions differ from the SNES, and the pad has no X, Y, L or R
+    fn get_bit(btn: Button) -> Option<u8> {
+        match btn {
+            Button::A => Some(0),
+            Button::B => Some(1),
+            Button::Select => Some(2),
+            Button::Start => Some(3),
+            Button::Up => Some(4),
+            Button::Down => Some(5),
+            Button::Left => Some(6),
+            Button::Right => Some(7),
+            _ => None,
+        }
     }
+}
 
-    pub fn on_release(&mut self, btn: Button) {
-        self.state |= 1u8 << (btn as u8);
+impl Controller for NESController {
+    fn on_press(&mut self, btn: Button) {
+        if let Some(bit) = Self::get_bit(btn) {
+            self.state &= !(1u8 << bit);
+            self.latch &= !(1u8 << bit);
+        }
     }
 
-    pub fn peek(&self) -> u8 {
-        self.shift & 1u8
+    fn on_release(&mut self, btn: Button) {
+        if let Some(bit) = Self::get_bit(btn) {
+            self.state |= 1u8 << bit;
+        }
     }
 
-    pub fn read(&self) -> u8 {
-        debug!("R");
+    fn peek(&self) -> u8 {
         self.shift & 1u8
     }
 
-    pub fn write(&mut self, latch: bool, clk: bool) {
+    fn write(&mut self, latch: bool, clk: bool) {
         debug!("W L={:?} C={:?}", latch, clk);
 
         if latch {
@@ -55,8 +182,9 @@ impl SNESController {
             self.latch = self.state;
         }
 
+        // reads after the eighth bit return 1
         if clk {
-            self.shift >>= 1;
+            self.shift = (self.shift >> 1) | 0x80;
         }
     }
 }
@@ -434,6 +434,14 @@ pub trait Bus {
     fn write(&mut self, addr: u16, val: u8);
 }
 
+// a read or write the CPU made on the bus
+#[derive(Clone, Copy, Debug)]
+pub struct BusAccess {
+    pub addr: u16,
+    pub val: u8,
+    pub write: bool,
+}
+
 pub struct W65C02S<BusType: Bus> {
     pub state: CPUState, // cpu state
     pub ir: Opcode,      // instruction register
@@ -447,6 +455,7 @@ pub struct W65C02S<BusType: Bus> {
     pub temp8: u8,       // temporary storage
     pub temp16: u16,     // temporary storage
     pub interrupt: bool, // an interrupt is available
+    pub accesses: Vec<BusAccess>, // bus reads and writes made in the last cycle
     pub bus: BusType,
 }
 
@@ -483,10 +492,19 @@ impl<BusType: Bus> W65C02S<BusType> {
             temp8: 0,
             temp16: 0,
             interrupt: false,
+            accesses: Vec::new(),
             bus: bus,
         }
     }
 
+    // pulls RESB: the CPU runs its reset sequence and starts again from the reset vector
+    pub fn reset(&mut self) {
+        self.state = CPUState::Init(0);
+        self.ir = (Instruction::NOP(0, 0), AddressMode::Implied);
+        self.tcu = 0;
+        self.p = (self.p | CPUFlag::IRQB as u8) & !(CPUFlag::Decimal as u8);
+    }
+
     pub fn is_halted(&self) -> bool {
         match self.state {
             CPUState::Halt => true,
@@ -495,10 +513,13 @@ impl<BusType: Bus> W65C02S<BusType> {
     }
 
     fn read(&mut self, addr: u16) -> u8 {
-        self.bus.read(addr)
+        let val = self.bus.read(addr);
+        self.accesses.push(BusAccess { addr, val, write: false });
+        val
     }
 
     fn write(&mut self, addr: u16, val: u8) {
+        self.accesses.push(BusAccess { addr, val, write: true });
         self.bus.write(addr, val)
     }
 
@@ -582,6 +603,7 @@ impl<BusType: Bus> W65C02S<BusType> {
 
     pub fn cycle(&mut self) {
         debug!("CPU: {:x?}", self);
+        self.accesses.clear();
 
         match self.state {
             CPUState::Init(c) => match c {
@@ -1,87 +1,179 @@
-use log::{debug, info};
+use log::{debug, info, warn};
 use std::fmt;
 use std::iter::FromIterator;
 
+use crate::components::charrom::CharacterRom;
+
 #[derive(Debug)]
 pub enum State {
     Idle,
     Busy(usize),
 }
 
-#[derive(Debug)]
+// instruction execution times in microseconds, from the datasheet (fosc = 270kHz)
+const CLEAR_HOME_MICROS: u64 = 1520;
+const EXECUTION_MICROS: u64 = 37;
+// the internal reset holds the busy flag for 10ms after power-on
+const POWER_ON_MICROS: u64 = 10_000;
+// the blinking cursor alternates with the character underneath it every 409.6ms
+const BLINK_MICROS: u64 = 409_600;
+
+// what to do when the CPU writes to the controller while it is still busy
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BusyCheck {
+    Off,
+    Report,
+    Break,
+}
+
+// terminal stand-ins for the eight CGRAM characters
+const CGRAM_PLACEHOLDERS: [char; 8] = ['⓪', '①', '②', '③', '④', '⑤', '⑥', '⑦'];
+
+#[derive(Clone, Copy, Debug)]
+pub struct Geometry {
+    pub columns: u8,
+    pub rows: u8,
+}
+
+impl Geometry {
+    pub fn parse(spec: &str) -> Option<Geometry> {
+        let mut parts = spec.split('x');
+        let columns = parts.next()?.parse().ok()?;
+        let rows = parts.next()?.parse().ok()?;
+
+        match (columns, rows) {
+            (16, 1) | (16, 2) | (20, 4) | (40, 2) => Some(Geometry { columns, rows }),
+            _ => None,
+        }
+    }
+
+    // DDRAM line and offset within it shown on each row.  rows alternate between the two
+    // DDRAM lines, so four-row panels show each line split across two rows.
+    fn row_start(&self, row: u8) -> (u8, u8) {
+        (0x40 * (row % 2), self.columns * (row / 2))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
 pub enum RegisterSelector {
     Instruction = 0,
     Data = 1,
 }
 
 pub struct HD44780U {
+    pub geometry: Geometry,
+    pub clock_hz: u32,
     pub state: State,
     pub addr: u8,
-    pub line1: Vec<u8>,
-    pub line2: Vec<u8>,
-    pub charset: Vec<char>,
+    pub eight_bit: bool,   // DL: 8-bit or 4-bit interface
+    pub two_line: bool,    // N: number of display lines
+    pub large_font: bool,  // F: 5x10 or 5x8 dots
+    pub low_nibble: bool,  // in 4-bit mode, the next transfer is the low nibble
+    pub high_nibble: u8,   // in 4-bit mode, the high nibble of a pending write
+    pub increment: bool,   // I/D: address moves right (or left) after each access
+    pub shift_display: bool, // S: display shifts with each write
+    pub display_on: bool,  // D
+    pub cursor_on: bool,   // C
+    pub blink_on: bool,    // B
+    pub shift: u8,         // current display shift, in characters
+    pub blink_phase: bool, // the blinking cursor currently shows a solid block
+    pub blink_cycles: usize, // cycles until the blinking cursor next changes phase
+    pub ddram: Vec<u8>,
+    pub cgram: Vec<u8>,
+    pub cgram_selected: bool, // the address counter points into CGRAM rather than DDRAM
+    pub rom: CharacterRom,
     pub updated: bool,
+    pub revision: u64, // counts changes that may be visible
     pub e: bool,
+    pub busy_check: BusyCheck,
+    pub busy_writes: u32,                // writes made while the busy flag was set
+    pub busy_violation: Option<String>, // pending break requested by BusyCheck::Break
 }
 
 impl fmt::Debug for HD44780U {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (line1, line2) = self.get_output();
-
         f.debug_struct("HD44780U")
             .field("state", &self.state)
             .field("addr", &self.addr)
-            .field("line1", &line1)
-            .field("line2", &line2)
+            .field("eight_bit", &self.eight_bit)
+            .field("low_nibble", &self.low_nibble)
+            .field("output", &self.get_output())
             .finish()
     }
 }
 
 impl HD44780U {
-    pub fn new() -> HD44780U {
-        let mut line1 = Vec::new();
-        line1.resize(40, ' ' as u8);
-
-        let mut line2 = Vec::new();
-        line2.resize(40, ' ' as u8);
-
-        let charset = vec![
-            ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ',
-            ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '!',
-            '"', '#', '$', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/', '0', '1', '2',
-            '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?', '@', 'A', 'B', 'C',
-            'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T',
-            'U', 'V', 'W', 'X', 'Y', 'Z', '[', '¥', ']', '^', '_', '`', 'a', 'b', 'c', 'd', 'e',
-            'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v',
-            'w', 'x', 'y', 'z', '{', '|', '}', '→', '←', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ',
-            ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ',
-            ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '｡', '｢', '｣', '､', '･', 'ｦ', 'ｧ', 'ｨ', 'ｩ',
-            'ｪ', 'ｫ', 'ｭ', 'ｪ', 'ｮ', 'ｯ', 'ｰ', 'ｱ', 'ｲ', 'ｳ', 'ｴ', 'ｵ', 'ｶ', 'ｷ', 'ｸ', 'ｹ', 'ｺ',
-            'ｻ', 'ｼ', 'ｽ', 'ｾ', 'ｿ', 'ﾀ', 'ﾁ', 'ﾂ', 'ﾃ', 'ﾄ', 'ﾅ', 'ﾆ', 'ﾇ', 'ﾈ', 'ﾉ', 'ﾊ', 'ﾋ',
-            'ﾌ', 'ﾍ', 'ﾎ', 'ﾏ', 'ﾐ', 'ﾑ', 'ﾒ', 'ﾓ', 'ﾔ', 'ﾕ', 'ﾖ', 'ﾗ', 'ﾘ', 'ﾙ', 'ﾚ', 'ﾛ', 'ﾜ',
-            'ﾝ', 'ﾞ', 'ﾟ', 'α', 'ä', 'β', 'ε', 'μ', 'δ', 'ρ', 'g', '√', '¹', 'ϳ', '×', '¢', '£',
-            'ñ', 'ö', 'p', 'q', 'θ', '∞', 'Ω', 'ü', '∑', 'π', 'x', 'y', '子', '万', '円', '÷', ' ',
-            '█',
-        ];
-
-        HD44780U {
-            // state: State::Busy(15000),
-            state: State::Busy(150),
+    pub fn new(geometry: Geometry, rom: CharacterRom, clock_hz: u32, busy_check: BusyCheck) -> HD44780U {
+        let mut ddram = Vec::new();
+        ddram.resize(0x80, b' ');
+
+        let cgram = vec![0u8; 0x40];
+
+        let mut dsp = HD44780U {
+            geometry,
+            clock_hz,
+            state: State::Idle,
             addr: 0,
-            line1: line1,
-            line2: line2,
-            charset: charset,
+            eight_bit: true,
+            two_line: false,
+            large_font: false,
+            low_nibble: false,
+            high_nibble: 0,
+            increment: true,
+            shift_display: false,
+            display_on: false,
+            cursor_on: false,
+            blink_on: false,
+            shift: 0,
+            blink_phase: false,
+            blink_cycles: 0,
+            ddram,
+            cgram,
+            cgram_selected: false,
+            rom,
             updated: false,
+            revision: 0,
             e: false,
-        }
+            busy_check,
+            busy_writes: 0,
+            busy_violation: None,
+        };
+        dsp.set_busy(POWER_ON_MICROS);
+        dsp.blink_cycles = dsp.to_cycles(BLINK_MICROS);
+        dsp
     }
 
-    pub fn peek(&self, rs: RegisterSelector, rw: bool, _e: bool) -> u8 {
-        if !rw {
-            panic!("attempt to read display without read bit set");
+    // a time given in microseconds, counted in cycles of the CPU clock
+    fn to_cycles(&self, micros: u64) -> usize {
+        (micros * self.clock_hz as u64 / 1_000_000) as usize
+    }
+
+    fn set_busy(&mut self, micros: u64) {
+        self.state = State::Busy(self.to_cycles(micros));
+    }
+
+    fn check_busy(&mut self, rs: &RegisterSelector, val: u8) {
+        let remaining = match self.state {
+            State::Busy(c) => c,
+            State::Idle => return,
+        };
+        if self.busy_check == BusyCheck::Off {
+            return;
         }
 
-        match rs {
+        self.busy_writes += 1;
+        let message = format!(
+            "write {:?} = {:02x} while busy for {} more cycles",
+            rs, val, remaining
+        );
+        warn!("LCD: {}", message);
+        if self.busy_check == BusyCheck::Break {
+            self.busy_violation = Some(message);
+        }
+    }
+
+    fn output(&self, rs: &RegisterSelector) -> u8 {
+        let result = match rs {
             RegisterSelector::Instruction => {
                 let mut result = self.addr;
                 if let State::Busy(_) = self.state {
@@ -90,41 +182,30 @@ impl HD44780U {
                 result
             }
             RegisterSelector::Data => {
-                let offset = (self.addr & 0x3F) as usize;
-                if self.addr & 0x40 == 0x00 {
-                    self.line1[offset]
+                if self.cgram_selected {
+                    self.cgram[self.addr as usize]
                 } else {
-                    self.line2[offset]
+                    self.ddram[self.addr as usize]
                 }
             }
+        };
+
+        if self.eight_bit {
+            result
+        } else if self.low_nibble {
+            // in 4-bit mode only D7-D4 are driven
+            result << 4
+        } else {
+            result & 0xF0
         }
     }
 
-    pub fn read(&self, rs: RegisterSelector, rw: bool, _e: bool) -> u8 {
+    pub fn peek(&self, rs: RegisterSelector, rw: bool, _e: bool) -> u8 {
         if !rw {
             panic!("attempt to read display without read bit set");
         }
-        
-        match rs {
-            RegisterSelector::Instruction => {
-                let mut result = self.addr;
-                if let State::Busy(_) = self.state {
-                    result |= 0x80;
-                }
-                debug!("R {:?} = {:02x}", rs, result);
-                result
-            }
-            RegisterSelector::Data => {
-                let offset = (self.addr & 0x3F) as usize;
-                let result = if self.addr & 0x40 == 0x00 {
-                    self.line1[offset]
-                } else {
-                    self.line2[offset]
-                };
-                debug!("R {:?} = {:02x}", rs, result);
-                result
-            }
-        }
+
+        self.output(&rs)
     }
 
     pub fn write(&mut self, rs: RegisterSelector, rw: bool, e: bool, val: u8) {
@@ -132,70 +213,266 @@ impl HD44780U {
         
         let last_e = self.e;
         self.e = e;
-        
+
+        // falling edge ends a read or triggers a write
+        if !last_e || self.e {
+            return;
+        }
+
+        if !rw {
+            self.check_busy(&rs, val);
+        }
+
+        if !self.eight_bit {
+            // in 4-bit mode every transfer takes two enable pulses, high nibble first
+            self.low_nibble = !self.low_nibble;
+            if self.low_nibble {
+                self.high_nibble = val & 0xF0;
+                return;
+            }
+        }
+
         if rw {
+            // data reads move the address counter just like writes
+            if let RegisterSelector::Data = rs {
+                self.addr = self.next_addr(self.addr, self.increment);
+                self.set_busy(EXECUTION_MICROS);
+            }
             return;
         }
 
-        // falling edge triggers write
-        if last_e && !self.e {
-            match rs {
-                RegisterSelector::Instruction => {
-                    if val & 0x80 == 0x80 {
-                        // set ddram addr
-                        self.addr = (val & 0x7f) % 80;
-                    } else if val & 0x40 == 0x40 {
-                        // set cgram addr
-                    } else if val & 0x20 == 0x20 {
-                        // function set
-                    } else if val & 0x10 == 0x10 {
-                        // cursor or display shift
-                    } else if val & 0x08 == 0x08 {
-                        // display on/off
-                    } else if val & 0x04 == 0x04 {
-                        // entry mode set
-                    } else if val & 0x02 == 0x02 {
-                        // return home
-                    } else if val & 0x01 == 0x01 {
-                        // clear display
-                        self.addr = 0;
-                        self.line1.iter_mut().for_each(|x| *x = ' ' as u8);
-                        self.line2.iter_mut().for_each(|x| *x = ' ' as u8);
+        let val = if self.eight_bit {
+            val
+        } else {
+            self.high_nibble | (val >> 4)
+        };
+
+        self.execute(rs, val);
+    }
+
+    // position of a DDRAM address among the 80 the address counter steps through: 0x00-0x4F in
+    // one-line mode, or 0x00-0x27 then 0x40-0x67 in two-line mode.  addresses outside those
+    // wrap into them.
+    fn ddram_index(&self, addr: u8) -> u8 {
+        if self.two_line {
+            (addr & 0x40 == 0x40) as u8 * 40 + (addr & 0x3F) % 40
+        } else {
+            addr % 80
+        }
+    }
+
+    fn ddram_addr(&self, index: u8) -> u8 {
+        if self.two_line && index >= 40 {
+            0x40 + index - 40
+        } else {
+            index
+        }
+    }
+
+    // the address counter wraps within CGRAM, or around DDRAM from any address
+    fn next_addr(&self, addr: u8, forward: bool) -> u8 {
+        if self.cgram_selected {
+            if forward {
+                (addr + 1) & 0x3F
+            } else {
+                addr.wrapping_sub(1) & 0x3F
+            }
+        } else {
+            let step = if forward { 1 } else { 79 };
+            self.ddram_addr((self.ddram_index(addr) + step) % 80)
+        }
+    }
+
+    // number of characters in each line of DDRAM
+    fn line_length(&self) -> u8 {
+        if self.two_line {
+            40
+        } else {
+            80
+        }
+    }
+
+    fn move_display(&mut self, left: bool) {
+        let len = self.line_length();
+        self.shift = if left {
+            (self.shift + 1) % len
+        } else {
+            (self.shift + len - 1) % len
+        };
+        self.set_updated();
+    }
+
+    fn execute(&mut self, rs: RegisterSelector, val: u8) {
+        match rs {
+            RegisterSelector::Instruction => {
+                self.set_busy(EXECUTION_MICROS);
+                if val & 0x80 == 0x80 {
+                    // set ddram addr
+                    self.addr = self.ddram_addr(self.ddram_index(val & 0x7f));
+                    self.cgram_selected = false;
+                    self.set_updated(); // the cursor moves
+                } else if val & 0x40 == 0x40 {
+                    // set cgram addr
+                    self.addr = val & 0x3f;
+                    self.cgram_selected = true;
+                    self.set_updated();
+                } else if val & 0x20 == 0x20 {
+                    // function set
+                    self.eight_bit = val & 0x10 == 0x10;
+                    self.two_line = val & 0x08 == 0x08;
+                    self.large_font = val & 0x04 == 0x04;
+                    self.low_nibble = false;
+                    if !self.cgram_selected {
+                        self.addr = self.ddram_addr(self.ddram_index(self.addr));
                     }
-                    self.state = State::Busy(37);
-                }
-                RegisterSelector::Data => {
-                    let offset = (self.addr & 0x3F) as usize;
-    
-                    if self.addr & 0x40 == 0x00 {
-                        self.line1[offset] = val;
-                    } else {
-                        self.line2[offset] = val;
-                    };
-    
-                    self.addr += 1;
-                    if self.addr & 0x40 == 0x00 {
-                        if self.addr > 40 {
-                            self.addr = 0x40;
-                        }
+                    self.shift %= self.line_length();
+                    self.set_updated();
+                } else if val & 0x10 == 0x10 {
+                    // cursor or display shift
+                    let right = val & 0x04 == 0x04;
+                    if val & 0x08 == 0x08 {
+                        self.move_display(!right);
                     } else {
-                        if self.addr > (0x40 + 40) {
-                            self.addr = 0x00;
-                        }
+                        self.addr = self.next_addr(self.addr, right);
+                        self.set_updated();
                     }
-    
-                    self.state = State::Busy(37);
-                    self.updated = true;
+                } else if val & 0x08 == 0x08 {
+                    // display on/off
+                    self.display_on = val & 0x04 == 0x04;
+                    self.cursor_on = val & 0x02 == 0x02;
+                    self.blink_on = val & 0x01 == 0x01;
+                    self.set_updated();
+                } else if val & 0x04 == 0x04 {
+                    // entry mode set
+                    self.increment = val & 0x02 == 0x02;
+                    self.shift_display = val & 0x01 == 0x01;
+                } else if val & 0x02 == 0x02 {
+                    // return home
+                    self.addr = 0;
+                    self.cgram_selected = false;
+                    self.shift = 0;
+                    self.set_updated();
+                    self.set_busy(CLEAR_HOME_MICROS);
+                } else if val & 0x01 == 0x01 {
+                    // clear display
+                    self.addr = 0;
+                    self.cgram_selected = false;
+                    self.shift = 0;
+                    self.increment = true;
+                    self.ddram.iter_mut().for_each(|x| *x = b' ');
+                    self.set_updated();
+                    self.set_busy(CLEAR_HOME_MICROS);
                 }
             }
+            RegisterSelector::Data => {
+                if self.cgram_selected {
+                    self.cgram[self.addr as usize] = val;
+                    self.addr = self.next_addr(self.addr, self.increment);
+                } else {
+                    self.ddram[self.addr as usize] = val;
+                    self.addr = self.next_addr(self.addr, self.increment);
+
+                    if self.shift_display {
+                        self.move_display(self.increment);
+                    }
+                }
+
+                self.set_busy(EXECUTION_MICROS);
+                self.set_updated();
+            }
+        }
+    }
+
+    // DDRAM address shown at a row and column, if anything is shown there
+    pub fn get_cell_addr(&self, row: u8, column: u8) -> Option<u8> {
+        let (base, offset) = self.geometry.row_start(row);
+
+        if !self.display_on || (base != 0 && !self.two_line) {
+            return None;
+        }
+
+        Some(base + (offset + column + self.shift) % self.line_length())
+    }
+
+    // the cursor follows the address counter, but only while it points into DDRAM
+    pub fn get_cursor_addr(&self) -> Option<u8> {
+        if self.cgram_selected {
+            None
+        } else {
+            Some(self.addr)
+        }
+    }
+
+    // row and column at which the cursor is shown, if it is on screen
+    pub fn get_cursor_cell(&self) -> Option<(u8, u8)> {
+        let addr = self.get_cursor_addr()?;
+        let columns = self.geometry.columns;
+        (0..self.geometry.rows)
+            .flat_map(|r| (0..columns).map(move |c| (r, c)))
+            .find(|&(r, c)| self.get_cell_addr(r, c) == Some(addr))
+    }
+
+    fn get_row(&self, row: u8) -> String {
+        String::from_iter((0..self.geometry.columns).map(|c| match self.get_cell_addr(row, c) {
+            Some(addr) => self.get_char(self.ddram[addr as usize]),
+            None => ' ',
+        }))
+    }
+
+    // index of the CGRAM character used for a character code, if any
+    pub fn get_cgram_index(&self, code: u8) -> Option<usize> {
+        if code >= 0x10 {
+            None
+        } else if self.large_font {
+            // 5x10 characters take two CGRAM slots, so only four are available
+            Some(((code >> 1) & 0x03) as usize)
+        } else {
+            Some((code & 0x07) as usize)
+        }
+    }
+
+    // dot rows of a character cell, from CGRAM or the character ROM.  the last row is where
+    // the underline cursor is drawn.
+    pub fn get_glyph(&self, code: u8) -> Vec<u8> {
+        let height = self.get_cell_height();
+        match self.get_cgram_index(code) {
+            Some(ix) => {
+                // 5x10 characters start every 16 bytes of CGRAM
+                let start = if self.large_font { ix * 16 } else { ix * 8 };
+                self.cgram[start..start + height].iter().map(|dots| dots & 0x1F).collect()
+            }
+            None if self.large_font => {
+                let mut glyph = self.rom.get_glyph(code).to_vec();
+                glyph.resize(height, 0);
+                glyph
+            }
+            None => self.rom.get_small_glyph(code).to_vec(),
+        }
+    }
+
+    // dot rows in each character cell, including the cursor row
+    pub fn get_cell_height(&self) -> usize {
+        if self.large_font {
+            11
+        } else {
+            8
         }
     }
 
-    pub fn get_output(&self) -> (String, String) {
-        (
-            String::from_iter(self.line1[..16].iter().map(|c| self.charset[*c as usize])),
-            String::from_iter(self.line2[..16].iter().map(|c| self.charset[*c as usize])),
-        )
+    fn get_char(&self, code: u8) -> char {
+        match self.get_cgram_index(code) {
+            Some(ix) => CGRAM_PLACEHOLDERS[ix],
+            None => self.rom.get_char(code),
+        }
+    }
+
+    pub fn get_output(&self) -> Vec<String> {
+        (0..self.geometry.rows).map(|r| self.get_row(r)).collect()
+    }
+
+    fn set_updated(&mut self) {
+        self.updated = true;
+        self.revision = self.revision.wrapping_add(1);
     }
 
     pub fn get_updated(&mut self) -> bool {
@@ -212,5 +489,102 @@ impl HD44780U {
             State::Busy(0) => State::Idle,
             State::Busy(c) => State::Busy(c - 1),
         };
+
+        if self.blink_cycles == 0 {
+            self.blink_phase = !self.blink_phase;
+            self.blink_cycles = self.to_cycles(BLINK_MICROS);
+            if self.blink_on {
+                self.set_updated();
+            }
+        } else {
+            self.blink_cycles -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_display() -> HD44780U {
+        HD44780U::new(Geometry { columns: 16, rows: 2 }, CharacterRom::A00, 1_000_000, BusyCheck::Off)
+    }
+
+    // one 8-bit transfer, on the falling edge of E
+    fn send(dsp: &mut HD44780U, rs: RegisterSelector, val: u8) {
+        dsp.write(rs, false, true, val);
+        dsp.write(rs, false, false, val);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn ddram_writes_wrap_from_the_top_address() {
+        for function in [0x30, 0x38] {
+            let mut dsp = new_display();
+            send(&mut dsp, RegisterSelector::Instruction, function);
+            send(&mut dsp, RegisterSelector::Instruction, 0x80 | 0x7F);
+            send(&mut dsp, RegisterSelector::Data, b'A');
+            send(&mut dsp, RegisterSelector::Data, b'B');
+            assert_eq!(dsp.addr, if function == 0x38 { 0x59 } else { 0x31 });
+        }
+    }
+
+    // writes one character at each address, returning where the address counter ends up
+    fn write_from(dsp: &mut HD44780U, addrs: &[u8]) -> Vec<u8> {
+        addrs
+            .iter()
+            .map(|addr| {
+                send(dsp, RegisterSelector::Instruction, 0x80 | addr);
+                send(dsp, RegisterSelector::Data, b'A');
+                dsp.addr
+            })
+            .collect()
+    }
+
+    #[test]
+    fn two_line_writes_wrap_between_the_lines() {
+        let mut dsp = new_display();
+        send(&mut dsp, RegisterSelector::Instruction, 0x38);
+        assert_eq!(write_from(&mut dsp, &[0x27, 0x67]), vec![0x40, 0x00]);
+
+        // entry mode set, decrementing
+        send(&mut dsp, RegisterSelector::Instruction, 0x04);
+        assert_eq!(write_from(&mut dsp, &[0x40, 0x00]), vec![0x27, 0x67]);
+    }
+
+    #[test]
+    fn descenders_fit_the_5x8_font() {
+        let mut dsp = new_display();
+        send(&mut dsp, RegisterSelector::Instruction, 0x30);
+        assert_eq!(dsp.get_glyph(0xE7), vec![0x00, 0x00, 0x0F, 0x11, 0x11, 0x0F, 0x01, 0x0E]);
+
+        send(&mut dsp, RegisterSelector::Instruction, 0x34);
+        assert_eq!(dsp.get_glyph(0xE7)[7..], [0x01, 0x01, 0x0E, 0x00]);
+    }
+
+    #[test]
+    fn two_line_addresses_skip_the_gaps() {
+        let mut dsp = new_display();
+        send(&mut dsp, RegisterSelector::Instruction, 0x38);
+
+        // unused addresses are taken as the same position in their line
+        send(&mut dsp, RegisterSelector::Instruction, 0x80 | 0x30);
+        assert_eq!(dsp.addr, 0x08);
+        send(&mut dsp, RegisterSelector::Instruction, 0x80 | 0x7F);
+        assert_eq!(dsp.addr, 0x57);
+    }
+
+    #[test]
+    fn one_line_addresses_wrap_at_0x4f() {
+        let mut dsp = new_display();
+        send(&mut dsp, RegisterSelector::Instruction, 0x30);
+        send(&mut dsp, RegisterSelector::Instruction, 0x80 | 0x4F);
+        send(&mut dsp, RegisterSelector::Data, b'A');
+        assert_eq!(dsp.addr, 0x00);
+        assert_eq!(dsp.ddram[0x4F], b'A');
+
+        // entry mode set, decrementing
+        send(&mut dsp, RegisterSelector::Instruction, 0x04);
+        send(&mut dsp, RegisterSelector::Data, b'B');
+        assert_eq!(dsp.addr, 0x4F);
+    }
+}
@@ -0,0 +1,192 @@
+use log::info;
+use std::fmt;
+use std::iter::FromIterator;
+
+use crate::components::display::RegisterSelector;
+
+pub const WIDTH: usize = 128;
+pub const HEIGHT: usize = 64;
+
+// columns driven by each controller
+const HALF_WIDTH: usize = 64;
+
+// status register bits
+const STATUS_OFF: u8 = 0x20;
+
+// one KS0108 controller, driving a 64x64 half of the panel.  RAM is eight pages of 64
+// columns, each byte holding eight vertical dots with the topmost in bit 0.
+pub struct KS0108Half {
+    pub display_on: bool,
+    pub start_line: u8, // RAM line shown at the top of the panel
+    pub page: u8,       // X address
+    pub column: u8,     // Y address, incremented by each data access
+    pub output: u8,     // output register: data reads return the previous read's contents
+    pub ram: Vec<u8>,
+}
+
+impl KS0108Half {
+    fn new() -> KS0108Half {
+        KS0108Half {
+            display_on: false,
+            start_line: 0,
+            page: 0,
+            column: 0,
+            output: 0,
+            ram: vec![0u8; 8 * HALF_WIDTH],
+        }
+    }
+
+    fn ram_index(&self) -> usize {
+        self.page as usize * HALF_WIDTH + self.column as usize
+    }
+
+    fn next_column(&mut self) {
+        self.column = (self.column + 1) % HALF_WIDTH as u8;
+    }
+
+    fn output(&self, rs: &RegisterSelector) -> u8 {
+        match rs {
+            // the controller is never busy, and is never seen mid-reset
+            RegisterSelector::Instruction => if self.display_on { 0 } else { STATUS_OFF },
+            RegisterSelector::Data => self.output,
+        }
+    }
+
+    fn execute(&mut self, rs: RegisterSelector, val: u8) {
+        match rs {
+            RegisterSelector::Instruction => {
+                if val & 0xC0 == 0xC0 {
+                    // display start line
+                    self.start_line = val & 0x3F;
+                } else if val & 0xF8 == 0xB8 {
+                    // set page (X address)
+                    self.page = val & 0x07;
+                } else if val & 0xC0 == 0x40 {
+                    // set column (Y address)
+                    self.column = val & 0x3F;
+                } else if val & 0xFE == 0x3E {
+                    // display on/off
+                    self.display_on = val & 0x01 == 0x01;
+                }
+            }
+            RegisterSelector::Data => {
+                let ix = self.ram_index();
+                self.ram[ix] = val;
+                self.next_column();
+            }
+        }
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> bool {
+        if !self.display_on {
+            return false;
+        }
+
+        let line = (y + self.start_line as usize) % HEIGHT;
+        self.ram[(line / 8) * HALF_WIDTH + x] & (1 << (line % 8)) != 0
+    }
+}
+
+// a 128x64 graphic LCD module: two KS0108s sharing the data and control lines, each enabled
+// by its own chip select
+pub struct KS0108 {
+    pub halves: [KS0108Half; 2],
+    pub updated: bool,
+    pub e: bool,
+}
+
+impl fmt::Debug for KS0108 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KS0108")
+            .field("e", &self.e)
+            .field("page", &[self.halves[0].page, self.halves[1].page])
+            .field("column", &[self.halves[0].column, self.halves[1].column])
+            .finish()
+    }
+}
+
+impl KS0108 {
+    pub fn new() -> KS0108 {
+        KS0108 {
+            halves: [KS0108Half::new(), KS0108Half::new()],
+            updated: false,
+            e: false,
+        }
+    }
+
+    fn selected(cs: [bool; 2]) -> impl Iterator<Item = usize> {
+        (0..2).filter(move |&ix| cs[ix])
+    }
+
+    // with both halves selected their outputs fight, and a low wins.  with neither, the bus
+    // floats high.
+    fn output(&self, rs: &RegisterSelector, cs: [bool; 2]) -> u8 {
+        Self::selected(cs).fold(0xFF, |acc, ix| acc & self.halves[ix].output(rs))
+    }
+
+    pub fn peek(&self, rs: RegisterSelector, rw: bool, _e: bool, cs: [bool; 2]) -> u8 {
+        if !rw {
+            panic!("attempt to read graphic display without read bit set");
+        }
+
+        self.output(&rs, cs)
+    }
+
+    pub fn write(&mut self, rs: RegisterSelector, rw: bool, e: bool, cs: [bool; 2], val: u8) {
+        info!("W {:?} {:?} = {:02x}", cs, rs, val);
+
+        let last_e = self.e;
+        self.e = e;
+
+        // falling edge ends a read or triggers a write
+        if !last_e || self.e {
+            return;
+        }
+
+        for ix in Self::selected(cs) {
+            let half = &mut self.halves[ix];
+            if rw {
+                // data reads load the output register and move to the next column
+                if let RegisterSelector::Data = rs {
+                    half.output = half.ram[half.ram_index()];
+                    half.next_column();
+                }
+            } else {
+                half.execute(rs, val);
+                self.updated = true;
+            }
+        }
+    }
+
+    pub fn get_pixel(&self, x: usize, y: usize) -> bool {
+        self.halves[x / HALF_WIDTH].get_pixel(x % HALF_WIDTH, y)
+    }
+
+    // the panel as rows of braille characters, each showing a 2x4 block of dots
+    pub fn get_output(&self) -> Vec<String> {
+        // braille dot bits, by row then column within the block
+        const DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+        (0..HEIGHT / 4)
+            .map(|row| {
+                String::from_iter((0..WIDTH / 2).map(|column| {
+                    let mut bits = 0;
+                    for (dy, dots) in DOTS.iter().enumerate() {
+                        for (dx, dot) in dots.iter().enumerate() {
+                            if self.get_pixel(column * 2 + dx, row * 4 + dy) {
+                                bits |= dot;
+                            }
+                        }
+                    }
+                    std::char::from_u32(0x2800 + bits).unwrap()
+                }))
+            })
+            .collect()
+    }
+
+    pub fn get_updated(&mut self) -> bool {
+        let result = self.updated;
+        self.updated = false;
+        result
+    }
+}
@@ -0,0 +1,63 @@
+use log::debug;
+
+// key labels by row and column, as printed on the common 4x4 membrane keypads
+const LABELS: [[char; 4]; 4] = [
+    ['1', '2', '3', 'A'],
+    ['4', '5', '6', 'B'],
+    ['7', '8', '9', 'C'],
+    ['*', '0', '#', 'D'],
+];
+
+// a 4x4 matrix keypad: each key joins a row line to a column line.  the host drives the rows
+// and reads the columns, which are pulled up, so a row driven low pulls down the columns of its
+// pressed keys.
+#[derive(Debug)]
+pub struct MatrixKeypad {
+    pub pressed: [[bool; 4]; 4], // by row, then column
+}
+
+impl MatrixKeypad {
+    pub fn new() -> MatrixKeypad {
+        MatrixKeypad {
+            pressed: [[false; 4]; 4],
+        }
+    }
+
+    // the row and column of a key
+    pub fn find_key(label: char) -> Option<(usize, usize)> {
+        let label = label.to_ascii_uppercase();
+        LABELS.iter().enumerate().find_map(|(row, labels)| {
+            labels.iter().position(|l| *l == label).map(|column| (row, column))
+        })
+    }
+
+    pub fn on_press(&mut self, label: char) {
+        if let Some((row, column)) = Self::find_key(label) {
+            debug!("press {}", label);
+            self.pressed[row][column] = true;
+        }
+    }
+
+    pub fn on_release(&mut self, label: char) {
+        if let Some((row, column)) = Self::find_key(label) {
+            debug!("release {}", label);
+            self.pressed[row][column] = false;
+        }
+    }
+
+    // the column lines in bits 0-3, given the row lines in bits 0-3
+    pub fn read_columns(&self, rows: u8) -> u8 {
+        let mut columns = 0x0F;
+        for (row, keys) in self.pressed.iter().enumerate() {
+            if rows & (1 << row) != 0 {
+                continue;
+            }
+            for (column, pressed) in keys.iter().enumerate() {
+                if *pressed {
+                    columns &= !(1 << column);
+                }
+            }
+        }
+        columns
+    }
+}
@@ -0,0 +1,122 @@
+// how long a light that was lit stays visible, like the eye's persistence of vision
+const PERSISTENCE_MICROS: u32 = 20_000;
+// the share of a window a light must be lit for to be seen, so that brief glitches while
+// multiplexed digits switch over don't show
+const MIN_DUTY_DIVISOR: u32 = 32;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LightsKind {
+    Leds,         // separate LEDs
+    Bar,          // an LED bar graph
+    SevenSegment, // multiplexed digits, segments a-g and the decimal point in bits 0-7
+}
+
+// a group of lights, in banks of up to eight: one bank of LEDs, or one bank per 7-segment
+// digit.  anything lit for long enough during a persistence window shows for the whole window,
+// so multiplexed digits and dimmed LEDs show steadily instead of flickering.
+#[derive(Debug)]
+pub struct Lights {
+    pub kind: LightsKind,
+    pub count: usize,         // lights in each bank
+    pub shown: Vec<u8>,       // as seen over the last window
+    lit: Vec<u8>,             // lit right now
+    changed: Vec<u32>,        // cycle in this window when each bank last changed
+    on_cycles: Vec<[u32; 8]>, // cycles each light has been lit for in this window
+    window_cycles: u32,
+    countdown: u32,
+    updated: bool,
+}
+
+impl Lights {
+    pub fn new(kind: LightsKind, banks: usize, count: usize, clock_hz: u32) -> Lights {
+        Lights {
+            kind,
+            count,
+            shown: vec![0; banks],
+            lit: vec![0; banks],
+            changed: vec![0; banks],
+            on_cycles: vec![[0; 8]; banks],
+            window_cycles: (PERSISTENCE_MICROS as u64 * clock_hz as u64 / 1_000_000) as u32,
+            countdown: 0,
+            updated: true,
+        }
+    }
+
+    fn elapsed(&self) -> u32 {
+        self.window_cycles - self.countdown
+    }
+
+    // adds the time since the bank last changed to its lit lights
+    fn accumulate(&mut self, bank: usize) {
+        let now = self.elapsed();
+        let lit = self.lit[bank];
+        for (bit, on) in self.on_cycles[bank].iter_mut().enumerate() {
+            if lit & (1 << bit) != 0 {
+                *on += now - self.changed[bank];
+            }
+        }
+        self.changed[bank] = now;
+    }
+
+    pub fn set(&mut self, bank: usize, lit: u8) {
+        if lit != self.lit[bank] {
+            self.accumulate(bank);
+            self.lit[bank] = lit;
+        }
+    }
+
+    // as drawn beside the display: LEDs as dots, a bar graph as blocks, and 7-segment digits
+    // three lines high
+    pub fn format(&self) -> Vec<String> {
+        let lit = |bank: usize, ix: usize| self.shown[bank] & (1 << ix) != 0;
+
+        match self.kind {
+            LightsKind::Leds => {
+                vec![(0..self.count).map(|ix| if lit(0, ix) { "●" } else { "○" }).collect::<Vec<_>>().join(" ")]
+            }
+            LightsKind::Bar => vec![(0..self.count).map(|ix| if lit(0, ix) { '█' } else { '░' }).collect()],
+            LightsKind::SevenSegment => {
+                // segments a-g and the decimal point, as bits 0-7
+                let segment = |bank: usize, ix: usize, ch: char| if lit(bank, ix) { ch } else { ' ' };
+                let banks = 0..self.shown.len();
+                vec![
+                    banks.clone().map(|d| format!(" {}  ", segment(d, 0, '_'))).collect(),
+                    banks.clone().map(|d| format!("{}{}{} ", segment(d, 5, '|'), segment(d, 6, '_'), segment(d, 1, '|'))).collect(),
+                    banks.map(|d| format!("{}{}{}{}", segment(d, 4, '|'), segment(d, 3, '_'), segment(d, 2, '|'), segment(d, 7, '.'))).collect(),
+                ]
+            }
+        }
+    }
+
+    pub fn get_updated(&mut self) -> bool {
+        let updated = self.updated;
+        self.updated = false;
+        updated
+    }
+
+    pub fn cycle(&mut self) {
+        if self.countdown > 0 {
+            self.countdown -= 1;
+            return;
+        }
+
+        let min_cycles = self.window_cycles / MIN_DUTY_DIVISOR;
+        for bank in 0..self.lit.len() {
+            self.accumulate(bank);
+            let shown = self.on_cycles[bank].iter().enumerate().fold(0, |acc, (bit, on)| {
+                if *on > min_cycles {
+                    acc | (1 << bit)
+                } else {
+                    acc
+                }
+            });
+            if shown != self.shown[bank] {
+                self.shown[bank] = shown;
+                self.updated = true;
+            }
+            self.on_cycles[bank] = [0; 8];
+            self.changed[bank] = 0;
+        }
+        self.countdown = self.window_cycles;
+    }
+}
@@ -1,13 +1,33 @@
+pub mod acia;
+pub mod charrom;
+pub mod cia;
 pub mod controller;
 pub mod cpu;
 pub mod display;
+pub mod glcd;
+pub mod keypad;
+pub mod led;
 pub mod periph;
+pub mod pins;
+pub mod ps2;
+pub mod pushbutton;
 pub mod ram;
 pub mod rom;
+pub mod serial;
 
-pub use controller::{SNESController, Button};
+pub use acia::W65C51;
+pub use charrom::CharacterRom;
+pub use cia::MOS6526;
+pub use controller::{Button, Controller, ControllerType};
 pub use cpu::{Bus, W65C02S};
-pub use display::{HD44780U, RegisterSelector};
-pub use periph::{W65C22, Ports, Port};
+pub use display::{HD44780U, RegisterSelector, Geometry, BusyCheck};
+pub use glcd::KS0108;
+pub use keypad::MatrixKeypad;
+pub use led::{Lights, LightsKind};
+pub use periph::{W65C22, Ports, Port, ControlLine};
+pub use pins::{Pin, PinDevice, Wired, PortPins, Nets, LcdPins, GlcdPins, ControllerPins, KeyboardPins, KeypadPins, LedPins, SegmentPins, data_bus, port_byte};
+pub use ps2::PS2Keyboard;
+pub use pushbutton::PushButton;
 pub use ram::RAM;
-pub use rom::ROM;
\ No newline at end of file
+pub use rom::ROM;
+pub use serial::{SerialLink, HostTerminal, ThreadedConsole};
\ No newline at end of file
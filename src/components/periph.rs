@@ -2,14 +2,27 @@ use log::debug;
 use std::cell::Cell;
 use std::fmt;
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Port {
     A,
     B,
 }
 
+#[derive(Debug)]
+pub enum ControlLine {
+    CA1,
+    CB1,
+    CB2,
+    Flag,
+    Cnt,
+    Sp,
+}
+
 #[derive(Debug)]
 enum Interrupts {
+    CA1 = 0x02,
+    SR = 0x04,
+    CB1 = 0x10,
     T1 = 0x40,
 }
 
@@ -17,6 +30,18 @@ pub trait Ports {
     fn peek(&self, port: Port) -> u8;
     fn read(&mut self, port: Port) -> u8;
     fn write(&mut self, port: Port, val: u8);
+    fn peek_control(&self, line: ControlLine) -> bool;
+    fn write_control(&mut self, line: ControlLine, val: bool);
+    // the data direction register of a port has changed, with outputs in its set bits
+    fn write_direction(&mut self, _port: Port, _outputs: u8) {}
+    // the level of every net, as bits of pins::Pin masks, where the pins are modeled
+    fn peek_pins(&self) -> u32 {
+        0
+    }
+    // the state of the pins, for the debugger
+    fn describe_pins(&self) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 #[allow(dead_code)]
@@ -25,10 +50,15 @@ pub struct W65C22<PortsType: Ports> {
     pub ora: u8,
     pub ddrb: u8,
     pub ddra: u8,
+    pub ira: u8,
+    pub irb: u8,
+    pub ca1: bool,
+    pub cb1: bool,
     pub t1c: u16,
     pub t1l: u16,
     pub t2c: u16,
     pub sr: u8,
+    pub sr_bits: u8, // bits shifted in since SR was last accessed
     pub acr: u8,
     pub pcr: u8,
     pub ifr: Cell<u8>,
@@ -43,6 +73,10 @@ impl<PortsType: Ports> fmt::Debug for W65C22<PortsType> {
             .field("ora", &self.ora)
             .field("ddrb", &self.ddrb)
             .field("ddra", &self.ddra)
+            .field("ira", &self.ira)
+            .field("irb", &self.irb)
+            .field("acr", &self.acr)
+            .field("pcr", &self.pcr)
             .finish()
     }
 }
@@ -54,10 +88,15 @@ impl<PortsType: Ports> W65C22<PortsType> {
             ora: 0,
             ddrb: 0,
             ddra: 0,
+            ira: 0,
+            irb: 0,
+            ca1: false,
+            cb1: false,
             t1c: 0,
             t1l: 0,
             t2c: 0,
             sr: 0,
+            sr_bits: 0,
             acr: 0,
             pcr: 0,
             ifr: Cell::new(0),
@@ -84,7 +123,98 @@ impl<PortsType: Ports> W65C22<PortsType> {
         self.ifr.replace(ifr);
     }
 
+    fn port_a_latching(&self) -> bool {
+        self.acr & 0x01 == 0x01
+    }
+
+    fn port_b_latching(&self) -> bool {
+        self.acr & 0x02 == 0x02
+    }
+
+    // ACR bits 4-2 = 011: shift in under control of an external clock on CB1, data on CB2
+    fn sr_external_in(&self) -> bool {
+        (self.acr >> 2) & 0x07 == 0x03
+    }
+
+    fn shift_in(&mut self) {
+        // called on each rising edge of CB1
+        let bit = self.ports.peek_control(ControlLine::CB2) as u8;
+        self.sr = (self.sr << 1) | bit;
+        self.sr_bits += 1;
+
+        if self.sr_bits == 8 {
+            self.sr_bits = 0;
+            self.set_interrupt(Interrupts::SR);
+        }
+    }
+
+    fn cycle_control_lines(&mut self) {
+        // PCR bit 0 (CA1) and bit 4 (CB1) select the active edge: 0 = negative, 1 = positive
+        let ca1 = self.ports.peek_control(ControlLine::CA1);
+        if ca1 != self.ca1 {
+            self.ca1 = ca1;
+            if ca1 == (self.pcr & 0x01 == 0x01) {
+                if self.port_a_latching() {
+                    self.ira = self.ports.peek(Port::A);
+                }
+                self.set_interrupt(Interrupts::CA1);
+            }
+        }
+
+        let cb1 = self.ports.peek_control(ControlLine::CB1);
+        if cb1 != self.cb1 {
+            self.cb1 = cb1;
+            if cb1 == (self.pcr & 0x10 == 0x10) {
+                if self.port_b_latching() {
+                    self.irb = self.ports.peek(Port::B);
+                }
+                self.set_interrupt(Interrupts::CB1);
+            }
+            if cb1 && self.sr_external_in() {
+                self.shift_in();
+            }
+        }
+    }
+
+    fn peek_port_a(&self) -> u8 {
+        let input = if self.port_a_latching() {
+            self.ira
+        } else {
+            self.ports.peek(Port::A)
+        };
+        (self.ora & self.ddra) | (input & !self.ddra)
+    }
+
+    fn peek_port_b(&self) -> u8 {
+        let input = if self.port_b_latching() {
+            self.irb
+        } else {
+            self.ports.peek(Port::B)
+        };
+        (self.orb & self.ddrb) | (input & !self.ddrb)
+    }
+
+    fn read_port_a(&mut self) -> u8 {
+        let input = if self.port_a_latching() {
+            self.ira
+        } else {
+            self.ports.read(Port::A)
+        };
+        (self.ora & self.ddra) | (input & !self.ddra)
+    }
+
+    fn read_port_b(&mut self) -> u8 {
+        let input = if self.port_b_latching() {
+            self.irb
+        } else {
+            self.ports.read(Port::B)
+        };
+        (self.orb & self.ddrb) | (input & !self.ddrb)
+    }
+
     pub fn cycle(&mut self) -> bool {
+        self.cycle_control_lines();
+
         if self.t1c > 0 {
             self.t1c -= 1;
         } else {
@@ -118,10 +248,8 @@ impl<PortsType: Ports> W65C22<PortsType> {
 
     pub fn peek(&self, addr: u16) -> u8 {
         match addr {
-            0x0 => (self.orb & self.ddrb) | (self.ports.peek(Port::B) & !self.ddrb),
-            0x1 => {
-                unimplemented!();
-            }
+            0x0 => self.peek_port_b(),
+            0x1 => self.peek_port_a(),
             0x2 => self.ddrb,
             0x3 => self.ddra,
             0x4 => (self.t1c & 0x00ff) as u8,
@@ -134,30 +262,25 @@ impl<PortsType: Ports> W65C22<PortsType> {
             0x9 => {
                 unimplemented!("W65C22 - Read T2C_H");
             }
-            0xA => {
-                unimplemented!("W65C22 - Read SR");
-            }
-            0xB => {
-                unimplemented!("W65C22 - Read ACR");
-            }
-            0xC => {
-                unimplemented!("W65C22 - Read PCR");
-            }
+            0xA => self.sr,
+            0xB => self.acr,
+            0xC => self.pcr,
             0xD => self.ifr.get(),
             0xE => self.ier,
-            0xF => {
-                (self.ora & self.ddra)
-                    | (self.ports.peek(Port::A) & !self.ddra)
-            }
+            0xF => self.peek_port_a(),
             _ => panic!("attempt to access invalid W65C22 register: {}", addr),
         }
     }
 
     pub fn read(&mut self, addr: u16) -> u8 {
         let data = match addr {
-            0x0 => (self.orb & self.ddrb) | (self.ports.read(Port::B) & !self.ddrb),
+            0x0 => {
+                self.clear_interrupt(Interrupts::CB1);
+                self.read_port_b()
+            }
             0x1 => {
-                unimplemented!();
+                self.clear_interrupt(Interrupts::CA1);
+                self.read_port_a()
             }
             0x2 => self.ddrb,
             0x3 => self.ddra,
@@ -178,20 +301,15 @@ impl<PortsType: Ports> W65C22<PortsType> {
                 unimplemented!("W65C22 - Read T2C_H");
             }
             0xA => {
-                unimplemented!("W65C22 - Read SR");
-            }
-            0xB => {
-                unimplemented!("W65C22 - Read ACR");
-            }
-            0xC => {
-                unimplemented!("W65C22 - Read PCR");
+                self.clear_interrupt(Interrupts::SR);
+                self.sr_bits = 0;
+                self.sr
             }
+            0xB => self.acr,
+            0xC => self.pcr,
             0xD => self.ifr.get(),
             0xE => self.ier,
-            0xF => {
-                (self.ora & self.ddra)
-                    | (self.ports.read(Port::A) & !self.ddra)
-            }
+            0xF => self.read_port_a(),
             _ => panic!("attempt to access invalid W65C22 register: {}", addr),
         };
         debug!("R @ {:04x} = {:02x}", addr, data);
@@ -202,17 +320,23 @@ impl<PortsType: Ports> W65C22<PortsType> {
         debug!("W @ {:04x} = {:02x}", addr, data);
         match addr {
             0x0 => {
+                self.clear_interrupt(Interrupts::CB1);
                 self.orb = data & self.ddrb;
                 self.ports.write(Port::B, self.orb);
             }
             0x1 => {
-                unimplemented!("W65C22 - Access to ORA w/ handshake");
+                // CA2 handshake output is not modeled
+                self.clear_interrupt(Interrupts::CA1);
+                self.ora = data & self.ddra;
+                self.ports.write(Port::A, self.ora);
             }
             0x2 => {
                 self.ddrb = data;
+                self.ports.write_direction(Port::B, data);
             }
             0x3 => {
                 self.ddra = data;
+                self.ports.write_direction(Port::A, data);
             }
             0x4 => {
                 self.t1l = (self.t1l & 0xff00) | (data as u16);
@@ -236,13 +360,16 @@ impl<PortsType: Ports> W65C22<PortsType> {
                 unimplemented!("W65C22 - Write T2C_H");
             }
             0xA => {
-                unimplemented!("W65C22 - Write SR");
+                // only shifting in is modeled, so a write just loads the register
+                self.clear_interrupt(Interrupts::SR);
+                self.sr_bits = 0;
+                self.sr = data;
             }
             0xB => {
                 self.acr = data;
             }
             0xC => {
-                unimplemented!("W65C22 - Write PCR");
+                self.pcr = data;
             }
             0xD => {
                 *self.ifr.get_mut() &= !data;
@@ -258,3 +385,103 @@ impl<PortsType: Ports> W65C22<PortsType> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // port inputs and control lines as the test sets them
+    #[derive(Default)]
+    struct TestPorts {
+        a: u8,
+        ca1: bool,
+        cb1: bool,
+        cb2: bool,
+    }
+
+    impl Ports for TestPorts {
+        fn peek(&self, port: Port) -> u8 {
+            match port {
+                Port::A => self.a,
+                Port::B => 0,
+            }
+        }
+
+        fn read(&mut self, port: Port) -> u8 {
+            self.peek(port)
+        }
+
+        fn write(&mut self, _port: Port, _val: u8) {}
+
+        fn peek_control(&self, line: ControlLine) -> bool {
+            match line {
+                ControlLine::CA1 => self.ca1,
+                ControlLine::CB1 => self.cb1,
+                ControlLine::CB2 => self.cb2,
+                _ => false,
+            }
+        }
+
+        fn write_control(&mut self, _line: ControlLine, _val: bool) {}
+    }
+
+    const IFR_CA1: u8 = Interrupts::CA1 as u8;
+    const IFR_SR: u8 = Interrupts::SR as u8;
+
+    #[test]
+    fn port_a_latches_on_the_active_ca1_edge() {
+        let mut via = W65C22::new(TestPorts { a: 0x12, ..Default::default() });
+        via.write(0xB, 0x01); // latch port A
+        via.write(0xC, 0x01); // on a rising CA1 edge
+        via.cycle();
+
+        via.ports.ca1 = true;
+        via.cycle();
+        assert_ne!(via.ifr.get() & IFR_CA1, 0);
+
+        via.ports.a = 0x34;
+        via.cycle();
+        assert_eq!(via.read(0x1), 0x12);
+        assert_eq!(via.ifr.get() & IFR_CA1, 0);
+
+        // the falling edge is not the active one
+        via.ports.ca1 = false;
+        via.cycle();
+        assert_eq!(via.ifr.get() & IFR_CA1, 0);
+        assert_eq!(via.read(0x1), 0x12);
+    }
+
+    #[test]
+    fn port_a_follows_the_pins_without_latching() {
+        let mut via = W65C22::new(TestPorts { a: 0x12, ..Default::default() });
+        via.write(0xC, 0x00); // interrupt on a falling CA1 edge
+        assert_eq!(via.read(0x1), 0x12);
+
+        via.ports.ca1 = true;
+        via.cycle();
+        assert_eq!(via.ifr.get() & IFR_CA1, 0);
+        via.ports.ca1 = false;
+        via.ports.a = 0x34;
+        via.cycle();
+        assert_ne!(via.ifr.get() & IFR_CA1, 0);
+        assert_eq!(via.read(0x1), 0x34);
+    }
+
+    #[test]
+    fn shift_register_takes_cb2_on_rising_cb1_edges() {
+        let mut via = W65C22::new(TestPorts::default());
+        via.write(0xB, 0x0C); // shift in under the external clock on CB1
+
+        for bit in (0..8).rev() {
+            via.ports.cb2 = 0xA5 & (1 << bit) != 0;
+            via.ports.cb1 = false;
+            via.cycle();
+            assert_eq!(via.ifr.get() & IFR_SR, 0);
+            via.ports.cb1 = true;
+            via.cycle();
+        }
+        assert_ne!(via.ifr.get() & IFR_SR, 0);
+        assert_eq!(via.read(0xA), 0xA5);
+        assert_eq!(via.ifr.get() & IFR_SR, 0);
+    }
+}
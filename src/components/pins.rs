@@ -0,0 +1,637 @@
+use std::fmt;
+
+use crate::components::{
+    ControlLine, Controller, Lights, MatrixKeypad, PS2Keyboard, Port, PushButton, RegisterSelector, HD44780U, KS0108,
+};
+
+// one of the nets on a VIA's or CIA's pins.  nets are handled as bit masks: PA0-PA7 in bits 0-7,
+// PB0-PB7 in bits 8-15, then the control lines.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pin(pub u8);
+
+impl Pin {
+    pub const CA1: Pin = Pin(16);
+    pub const CB1: Pin = Pin(17);
+    pub const CB2: Pin = Pin(18);
+    pub const SP: Pin = Pin(19);
+    pub const CNT: Pin = Pin(20);
+    pub const FLAG: Pin = Pin(21);
+
+    // every net, PA0 to FLAG
+    pub const ALL: u32 = (1 << 22) - 1;
+
+    pub fn port(port: Port, bit: u8) -> Pin {
+        match port {
+            Port::A => Pin(bit),
+            Port::B => Pin(8 + bit),
+        }
+    }
+
+    pub fn control(line: ControlLine) -> Pin {
+        match line {
+            ControlLine::CA1 => Pin::CA1,
+            ControlLine::CB1 => Pin::CB1,
+            ControlLine::CB2 => Pin::CB2,
+            ControlLine::Sp => Pin::SP,
+            ControlLine::Cnt => Pin::CNT,
+            ControlLine::Flag => Pin::FLAG,
+        }
+    }
+
+    // e.g. "pa3", "pb0", "ca1" or "sp"
+    pub fn parse(s: &str) -> Option<Pin> {
+        match s {
+            "ca1" => return Some(Pin::CA1),
+            "cb1" => return Some(Pin::CB1),
+            "cb2" => return Some(Pin::CB2),
+            "sp" => return Some(Pin::SP),
+            "cnt" => return Some(Pin::CNT),
+            "flag" => return Some(Pin::FLAG),
+            _ => {}
+        }
+
+        let port = match s.get(..2)? {
+            "pa" => Port::A,
+            "pb" => Port::B,
+            _ => return None,
+        };
+        match s[2..].parse() {
+            Ok(bit) if bit < 8 => Some(Pin::port(port, bit)),
+            _ => None,
+        }
+    }
+
+    pub fn mask(self) -> u32 {
+        1 << self.0
+    }
+
+    pub fn is_high(self, levels: u32) -> bool {
+        levels & self.mask() != 0
+    }
+
+    // the mask and levels for driving this pin to a level
+    pub fn drive(self, high: bool) -> (u32, u32) {
+        (self.mask(), if high { self.mask() } else { 0 })
+    }
+}
+
+impl fmt::Display for Pin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Pin::CA1 => write!(f, "CA1"),
+            Pin::CB1 => write!(f, "CB1"),
+            Pin::CB2 => write!(f, "CB2"),
+            Pin::SP => write!(f, "SP"),
+            Pin::CNT => write!(f, "CNT"),
+            Pin::FLAG => write!(f, "FLAG"),
+            Pin(n) if n < 8 => write!(f, "PA{}", n),
+            Pin(n) => write!(f, "PB{}", n - 8),
+        }
+    }
+}
+
+// what a net is at: driven or pulled high or low, or floating, which reads low
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Level {
+    Low,
+    High,
+    Floating,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Low => write!(f, "0"),
+            Level::High => write!(f, "1"),
+            Level::Floating => write!(f, "z"),
+        }
+    }
+}
+
+// nets from a port's byte, and back
+pub fn port_mask(port: Port, val: u8) -> u32 {
+    (val as u32) << Pin::port(port, 0).0
+}
+
+pub fn port_byte(port: Port, levels: u32) -> u8 {
+    (levels >> Pin::port(port, 0).0) as u8
+}
+
+// a device wired to a VIA's pins
+pub trait PinDevice {
+    fn name(&self) -> &'static str;
+    // the nets the device drives, and the levels it drives them to.  open-collector outputs only
+    // ever drive low.
+    fn drive(&self) -> (u32, u32);
+    // nets the device pulls up or down while nothing drives them
+    fn pull_ups(&self) -> u32 {
+        0
+    }
+    fn pull_downs(&self) -> u32 {
+        0
+    }
+    // sees the level of every net, each time the VIA writes to its ports
+    fn update(&mut self, levels: u32);
+}
+
+// a device, the pins it is wired to, and the net levels it last saw
+pub struct Wired<D, P> {
+    pub dev: D,
+    pub pins: P,
+    pub levels: u32,
+}
+
+impl<D, P> Wired<D, P> {
+    pub fn new(dev: D, pins: P) -> Wired<D, P> {
+        Wired { dev, pins, levels: 0 }
+    }
+}
+
+// data lines of a parallel bus, by bit, where they are connected
+fn gather(levels: u32, data: &[Option<Pin>; 8]) -> u8 {
+    data.iter().enumerate().fold(0, |acc, (bit, pin)| match pin {
+        Some(pin) if pin.is_high(levels) => acc | (1 << bit),
+        _ => acc,
+    })
+}
+
+fn scatter(val: u8, data: &[Option<Pin>; 8]) -> (u32, u32) {
+    data.iter().enumerate().fold((0, 0), |(mask, levels), (bit, pin)| match pin {
+        Some(pin) => {
+            let (m, l) = pin.drive(val & (1 << bit) != 0);
+            (mask | m, levels | l)
+        }
+        None => (mask, levels),
+    })
+}
+
+// D0-D7 on eight consecutive pins of a port, optionally leaving the low four unconnected
+pub fn data_bus(port: Port, upper_nibble_only: bool) -> [Option<Pin>; 8] {
+    let mut data = [None; 8];
+    let first = if upper_nibble_only { 4 } else { 0 };
+    for (bit, pin) in data.iter_mut().enumerate().skip(first) {
+        *pin = Some(Pin::port(port, bit as u8));
+    }
+    data
+}
+
+pub struct LcdPins {
+    pub rs: Pin,
+    pub rw: Pin,
+    pub e: Pin,
+    pub data: [Option<Pin>; 8], // D0-D7, unconnected in 4-bit mode
+}
+
+impl LcdPins {
+    fn get_control(&self, levels: u32) -> (RegisterSelector, bool, bool) {
+        (
+            if self.rs.is_high(levels) {
+                RegisterSelector::Data
+            } else {
+                RegisterSelector::Instruction
+            },
+            self.rw.is_high(levels),
+            self.e.is_high(levels),
+        )
+    }
+}
+
+// the LCDs drive their data lines while R/W selects a read and E is high
+impl PinDevice for Wired<HD44780U, LcdPins> {
+    fn name(&self) -> &'static str {
+        "lcd"
+    }
+
+    fn drive(&self) -> (u32, u32) {
+        match self.pins.get_control(self.levels) {
+            (rs, true, true) => scatter(self.dev.peek(rs, true, true), &self.pins.data),
+            _ => (0, 0),
+        }
+    }
+
+    fn update(&mut self, levels: u32) {
+        self.levels = levels;
+        let (rs, rw, e) = self.pins.get_control(levels);
+        self.dev.write(rs, rw, e, gather(levels, &self.pins.data));
+    }
+}
+
+pub struct GlcdPins {
+    pub lcd: LcdPins,
+    pub cs: [Pin; 2],
+}
+
+impl PinDevice for Wired<KS0108, GlcdPins> {
+    fn name(&self) -> &'static str {
+        "glcd"
+    }
+
+    fn drive(&self) -> (u32, u32) {
+        let cs = [self.pins.cs[0].is_high(self.levels), self.pins.cs[1].is_high(self.levels)];
+        match self.pins.lcd.get_control(self.levels) {
+            (rs, true, true) => scatter(self.dev.peek(rs, true, true, cs), &self.pins.lcd.data),
+            _ => (0, 0),
+        }
+    }
+
+    fn update(&mut self, levels: u32) {
+        self.levels = levels;
+        let (rs, rw, e) = self.pins.lcd.get_control(levels);
+        let cs = [self.pins.cs[0].is_high(levels), self.pins.cs[1].is_high(levels)];
+        self.dev.write(rs, rw, e, cs, gather(levels, &self.pins.lcd.data));
+    }
+}
+
+pub struct ControllerPins {
+    pub latch: Pin,
+    pub clk: Pin,
+    pub data: Pin,
+}
+
+impl PinDevice for Wired<Box<dyn Controller>, ControllerPins> {
+    fn name(&self) -> &'static str {
+        "controller"
+    }
+
+    fn drive(&self) -> (u32, u32) {
+        self.pins.data.drive(self.dev.peek() != 0)
+    }
+
+    // the shift register loads while LATCH is high and moves on each rising edge of CLK, so it is
+    // only told about those, not about every change to the other nets
+    fn update(&mut self, levels: u32) {
+        let latch = self.pins.latch.is_high(levels);
+        let latch_changed = latch != self.pins.latch.is_high(self.levels);
+        let clk_rose = self.pins.clk.is_high(levels) && !self.pins.clk.is_high(self.levels);
+        self.levels = levels;
+        if latch_changed || clk_rose {
+            self.dev.write(latch, clk_rose);
+        }
+    }
+}
+
+pub struct KeyboardPins {
+    pub clock: Pin,
+    pub data: Pin,
+}
+
+impl PinDevice for Wired<PS2Keyboard, KeyboardPins> {
+    fn name(&self) -> &'static str {
+        "keyboard"
+    }
+
+    fn drive(&self) -> (u32, u32) {
+        let mut mask = 0;
+        if !self.dev.clock {
+            mask |= self.pins.clock.mask();
+        }
+        if !self.dev.data {
+            mask |= self.pins.data.mask();
+        }
+        (mask, 0)
+    }
+
+    fn pull_ups(&self) -> u32 {
+        self.pins.clock.mask() | self.pins.data.mask()
+    }
+
+    fn update(&mut self, levels: u32) {
+        self.levels = levels;
+    }
+}
+
+pub struct KeypadPins {
+    pub rows: [Pin; 4],
+    pub columns: [Pin; 4],
+}
+
+// rows 0-3 on the low four pins of a port and columns 0-3 on the high four
+impl KeypadPins {
+    pub fn on_port(port: Port) -> KeypadPins {
+        KeypadPins {
+            rows: [0, 1, 2, 3].map(|bit| Pin::port(port, bit)),
+            columns: [4, 5, 6, 7].map(|bit| Pin::port(port, bit)),
+        }
+    }
+}
+
+impl PinDevice for Wired<MatrixKeypad, KeypadPins> {
+    fn name(&self) -> &'static str {
+        "keypad"
+    }
+
+    fn drive(&self) -> (u32, u32) {
+        let rows = self.pins.rows.iter().enumerate().fold(0, |acc, (row, pin)| {
+            acc | ((pin.is_high(self.levels) as u8) << row)
+        });
+        let columns = self.dev.read_columns(rows);
+        let mask = self.pins.columns.iter().enumerate().fold(0, |acc, (column, pin)| {
+            if columns & (1 << column) == 0 {
+                acc | pin.mask()
+            } else {
+                acc
+            }
+        });
+        (mask, 0)
+    }
+
+    // rows are pulled up too, so that only rows driven low are scanned
+    fn pull_ups(&self) -> u32 {
+        self.pins.rows.iter().chain(self.pins.columns.iter()).fold(0, |acc, pin| acc | pin.mask())
+    }
+
+    fn update(&mut self, levels: u32) {
+        self.levels = levels;
+    }
+}
+
+impl PinDevice for Wired<PushButton, Pin> {
+    fn name(&self) -> &'static str {
+        "button"
+    }
+
+    fn drive(&self) -> (u32, u32) {
+        if self.dev.output {
+            (0, 0)
+        } else {
+            (self.pins.mask(), 0)
+        }
+    }
+
+    fn pull_ups(&self) -> u32 {
+        self.pins.mask()
+    }
+
+    fn update(&mut self, levels: u32) {
+        self.levels = levels;
+    }
+}
+
+// LEDs from their pins to ground, lit while the pin is high
+pub struct LedPins {
+    pub leds: Vec<Pin>,
+}
+
+impl PinDevice for Wired<Lights, LedPins> {
+    fn name(&self) -> &'static str {
+        "leds"
+    }
+
+    fn drive(&self) -> (u32, u32) {
+        (0, 0)
+    }
+
+    fn update(&mut self, levels: u32) {
+        self.levels = levels;
+        let lit = self.pins.leds.iter().enumerate().fold(0, |acc, (ix, pin)| {
+            acc | ((pin.is_high(levels) as u8) << ix)
+        });
+        self.dev.set(0, lit);
+    }
+}
+
+// common cathode digits: a segment lights while its pin is high and its digit's pin is low.  a
+// single digit may have its cathode tied to ground instead.
+pub struct SegmentPins {
+    pub segments: [Option<Pin>; 8], // a-g, then the decimal point
+    pub digits: Vec<Pin>,
+}
+
+impl PinDevice for Wired<Lights, SegmentPins> {
+    fn name(&self) -> &'static str {
+        "segments"
+    }
+
+    fn drive(&self) -> (u32, u32) {
+        (0, 0)
+    }
+
+    fn update(&mut self, levels: u32) {
+        self.levels = levels;
+        let segments = gather(levels, &self.pins.segments);
+        if self.pins.digits.is_empty() {
+            self.dev.set(0, segments);
+        }
+        for (ix, pin) in self.pins.digits.iter().enumerate() {
+            self.dev.set(ix, if pin.is_high(levels) { 0 } else { segments });
+        }
+    }
+}
+
+// the chip's side of its pins, and what was found when every driver was put together
+pub struct PortPins {
+    pub chip: &'static str,
+    pub outputs: u32,  // nets the chip drives
+    pub levels: u32,   // levels the chip drives them to
+    pub pull_ups: u32, // nets pulled up or down on the board
+    pub pull_downs: u32,
+    pub controls: [Pin; 3],
+    pub contention: Option<String>,
+    pub contentions: u64,
+}
+
+// the drivers and pulls on every net, gathered from the VIA and each device
+#[derive(Clone, Copy, Default)]
+pub struct Nets {
+    pub high: u32,
+    pub low: u32,
+    pub pull_ups: u32,
+    pub pull_downs: u32,
+}
+
+impl Nets {
+    pub fn add(&mut self, (mask, levels): (u32, u32), pull_ups: u32, pull_downs: u32) {
+        self.high |= mask & levels;
+        self.low |= mask & !levels;
+        self.pull_ups |= pull_ups;
+        self.pull_downs |= pull_downs;
+    }
+
+    // a driven low wins over a driven high, and undriven nets follow their pull, reading low
+    // while floating
+    pub fn levels(&self) -> u32 {
+        (self.high | (self.pull_ups & !self.pull_downs)) & !self.low
+    }
+
+    // undriven nets pulled neither way, or both ways at once
+    pub fn floating(&self) -> u32 {
+        Pin::ALL & !(self.high | self.low) & !(self.pull_ups ^ self.pull_downs)
+    }
+
+    pub fn level(&self, pin: Pin) -> Level {
+        if self.floating() & pin.mask() != 0 {
+            Level::Floating
+        } else if pin.is_high(self.levels()) {
+            Level::High
+        } else {
+            Level::Low
+        }
+    }
+
+    pub fn contended(&self) -> u32 {
+        self.high & self.low
+    }
+}
+
+impl PortPins {
+    pub fn new() -> PortPins {
+        PortPins {
+            chip: "via",
+            outputs: 0,
+            levels: 0,
+            pull_ups: 0,
+            pull_downs: 0,
+            controls: [Pin::CA1, Pin::CB1, Pin::CB2],
+            contention: None,
+            contentions: 0,
+        }
+    }
+
+    // a CIA's serial port and FLAG lines idle high, through pull-ups on the board
+    pub fn cia() -> PortPins {
+        PortPins {
+            chip: "cia",
+            pull_ups: Pin::SP.mask() | Pin::CNT.mask() | Pin::FLAG.mask(),
+            controls: [Pin::SP, Pin::CNT, Pin::FLAG],
+            ..PortPins::new()
+        }
+    }
+
+    pub fn set_outputs(&mut self, port: Port, outputs: u8) {
+        let mask = port_mask(port, 0xFF);
+        self.outputs = (self.outputs & !mask) | port_mask(port, outputs);
+    }
+
+    pub fn set_levels(&mut self, port: Port, val: u8) {
+        let mask = port_mask(port, 0xFF);
+        self.levels = (self.levels & !mask) | port_mask(port, val);
+    }
+
+    // control lines the chip writes are open-drain: writing a one lets go of the line
+    pub fn set_control(&mut self, pin: Pin, high: bool) {
+        if high {
+            self.outputs &= !pin.mask();
+        } else {
+            self.outputs |= pin.mask();
+            self.levels &= !pin.mask();
+        }
+    }
+
+    pub fn nets(&self) -> Nets {
+        let mut nets = Nets::default();
+        nets.add((self.outputs, self.levels), self.pull_ups, self.pull_downs);
+        nets
+    }
+
+    // records the first contended net, naming everything driving it
+    pub fn check(&mut self, nets: &Nets, drivers: &[(&'static str, (u32, u32))]) {
+        let contended = nets.contended();
+        if contended == 0 {
+            return;
+        }
+
+        self.contentions += 1;
+        if self.contention.is_some() {
+            return;
+        }
+
+        let pin = Pin(contended.trailing_zeros() as u8);
+        let mut message = format!("{} driven by", pin);
+        let chip = (self.chip, (self.outputs, self.levels));
+        for (name, (mask, levels)) in std::iter::once(&chip).chain(drivers.iter()) {
+            if mask & pin.mask() != 0 {
+                message.push_str(&format!(" {}={}", name, pin.is_high(*levels) as u8));
+            }
+        }
+        self.contention = Some(message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{Button, ControllerType};
+
+    #[test]
+    fn low_wins_and_contention_names_every_driver() {
+        let mut pins = PortPins::new();
+        pins.set_outputs(Port::B, 0x01);
+        pins.set_levels(Port::B, 0x01);
+        let lcd = Pin::port(Port::B, 0).drive(false);
+
+        let mut nets = pins.nets();
+        nets.add(lcd, 0, 0);
+        assert!(!Pin::port(Port::B, 0).is_high(nets.levels()));
+
+        pins.check(&nets, &[("lcd", lcd), ("leds", (0, 0))]);
+        pins.check(&nets, &[("lcd", lcd), ("leds", (0, 0))]);
+        assert_eq!(pins.contention.as_deref(), Some("PB0 driven by via=1 lcd=0"));
+        assert_eq!(pins.contentions, 2);
+    }
+
+    // the nets once the VIA drives `outputs` to `levels` and the device has seen them
+    fn settle(pins: &mut PortPins, dev: &mut dyn PinDevice, outputs: u32, levels: u32) -> u32 {
+        pins.outputs = outputs;
+        pins.levels = levels;
+        let mut nets = pins.nets();
+        nets.add(dev.drive(), dev.pull_ups(), dev.pull_downs());
+        dev.update(nets.levels());
+
+        let mut nets = pins.nets();
+        nets.add(dev.drive(), dev.pull_ups(), dev.pull_downs());
+        nets.levels()
+    }
+
+    #[test]
+    fn controllers_shift_on_clock_edges_only() {
+        let mut con = ControllerType::Snes.create();
+        con.on_press(Button::Y);
+        let mut con = Wired::new(con, ControllerPins { latch: Pin(1), clk: Pin(2), data: Pin(0) });
+        let mut via = PortPins::new();
+        let outputs = port_mask(Port::A, 0x06) | port_mask(Port::B, 0xFF);
+
+        // pulse LATCH to read B, then raise CLK to read Y, the second button
+        settle(&mut via, &mut con, outputs, Pin(1).mask());
+        assert!(Pin(0).is_high(settle(&mut via, &mut con, outputs, 0)));
+        assert!(!Pin(0).is_high(settle(&mut via, &mut con, outputs, Pin(2).mask())));
+
+        // writes to the LCD on port B while CLK stays high don't move the shift register
+        for val in [0x41, 0x42] {
+            let levels = settle(&mut via, &mut con, outputs, Pin(2).mask() | port_mask(Port::B, val));
+            assert!(!Pin(0).is_high(levels));
+        }
+
+        // but the next rising edge does
+        settle(&mut via, &mut con, outputs, 0);
+        assert!(Pin(0).is_high(settle(&mut via, &mut con, outputs, Pin(2).mask())));
+    }
+
+    #[test]
+    fn keypad_scans_only_rows_driven_low() {
+        let mut keypad = MatrixKeypad::new();
+        keypad.on_press('4'); // row 1, column 0
+        keypad.on_press('2'); // row 0, column 1
+        let mut keypad = Wired::new(keypad, KeypadPins::on_port(Port::A));
+        let mut via = PortPins::new();
+
+        // row 0 driven low, rows 1-3 left floating
+        let levels = settle(&mut via, &mut keypad, Pin(0).mask(), 0);
+        assert_eq!(port_byte(Port::A, levels), 0xDE);
+    }
+
+    #[test]
+    fn undriven_nets_follow_their_pulls() {
+        let (up, down, both, none) = (Pin(0), Pin(1), Pin(2), Pin(3));
+        let mut nets = Nets::default();
+        nets.add((0, 0), up.mask() | both.mask(), down.mask() | both.mask());
+        assert_eq!(nets.level(up), Level::High);
+        assert_eq!(nets.level(down), Level::Low);
+        assert_eq!(nets.level(both), Level::Floating);
+        assert_eq!(nets.level(none), Level::Floating);
+        assert!(!none.is_high(nets.levels()));
+
+        // a driver overrides the pull
+        nets.add(up.drive(false), 0, 0);
+        assert_eq!(nets.level(up), Level::Low);
+    }
+}
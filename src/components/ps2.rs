@@ -0,0 +1,190 @@
+use log::debug;
+use std::collections::VecDeque;
+
+// set 2 scan codes for keys that don't type a character.  extended keys carry their 0xE0
+// prefix in the high byte.
+pub const LEFT_SHIFT: u16 = 0x12;
+pub const ENTER: u16 = 0x5A;
+pub const BACKSPACE: u16 = 0x66;
+pub const TAB: u16 = 0x0D;
+pub const UP: u16 = 0xE075;
+pub const DOWN: u16 = 0xE072;
+pub const LEFT: u16 = 0xE06B;
+pub const RIGHT: u16 = 0xE074;
+pub const HOME: u16 = 0xE06C;
+pub const END: u16 = 0xE069;
+pub const PAGE_UP: u16 = 0xE07D;
+pub const PAGE_DOWN: u16 = 0xE07A;
+pub const INSERT: u16 = 0xE070;
+pub const DELETE: u16 = 0xE071;
+
+// US layout: each key's character unshifted and shifted
+const KEYS: [(u8, char, char); 47] = [
+    (0x1C, 'a', 'A'), (0x32, 'b', 'B'), (0x21, 'c', 'C'), (0x23, 'd', 'D'), (0x24, 'e', 'E'),
+    (0x2B, 'f', 'F'), (0x34, 'g', 'G'), (0x33, 'h', 'H'), (0x43, 'i', 'I'), (0x3B, 'j', 'J'),
+    (0x42, 'k', 'K'), (0x4B, 'l', 'L'), (0x3A, 'm', 'M'), (0x31, 'n', 'N'), (0x44, 'o', 'O'),
+    (0x4D, 'p', 'P'), (0x15, 'q', 'Q'), (0x2D, 'r', 'R'), (0x1B, 's', 'S'), (0x2C, 't', 'T'),
+    (0x3C, 'u', 'U'), (0x2A, 'v', 'V'), (0x1D, 'w', 'W'), (0x22, 'x', 'X'), (0x35, 'y', 'Y'),
+    (0x1A, 'z', 'Z'),
+    (0x16, '1', '!'), (0x1E, '2', '@'), (0x26, '3', '#'), (0x25, '4', '$'), (0x2E, '5', '%'),
+    (0x36, '6', '^'), (0x3D, '7', '&'), (0x3E, '8', '*'), (0x46, '9', '('), (0x45, '0', ')'),
+    (0x0E, '`', '~'), (0x4E, '-', '_'), (0x55, '=', '+'), (0x54, '[', '{'), (0x5B, ']', '}'),
+    (0x5D, '\\', '|'), (0x4C, ';', ':'), (0x52, '\'', '"'), (0x41, ',', '<'), (0x49, '.', '>'),
+    (0x4A, '/', '?'),
+];
+
+// the key that types a character, and whether shift is needed
+pub fn get_char_key(c: char) -> Option<(u16, bool)> {
+    if c == ' ' {
+        return Some((0x29, false));
+    }
+
+    KEYS.iter().find_map(|&(code, plain, shifted)| {
+        if c == plain {
+            Some((code as u16, false))
+        } else if c == shifted {
+            Some((code as u16, true))
+        } else {
+            None
+        }
+    })
+}
+
+// 12.5kHz clock, with data changing halfway through the high half of each clock period
+const QUARTER_PERIOD_MICROS: usize = 20;
+const BYTE_GAP_MICROS: usize = 500;
+
+// quarter clock periods in an 11-bit frame
+const FRAME_STEPS: usize = 44;
+
+// a keyboard sending scan code set 2 bytes to the host: a start bit, eight data bits LSB first,
+// odd parity and a stop bit, read by the host on each falling clock edge.  the host never
+// inhibits the clock or sends commands.
+#[derive(Debug)]
+pub struct PS2Keyboard {
+    pub queue: VecDeque<u8>, // bytes waiting to be sent
+    pub clock: bool,         // open-collector lines, high when released
+    pub data: bool,
+    frame: u16,       // the byte being sent, framed, start bit in bit 0
+    step: usize,      // quarter clock periods into the frame, or 0 between frames
+    countdown: usize, // cycles until the next step
+    quarter_cycles: usize,
+    gap_cycles: usize,
+}
+
+impl PS2Keyboard {
+    pub fn new(clock_hz: u32) -> PS2Keyboard {
+        // at least a cycle per step, however slow the clock
+        let to_cycles = |micros: usize| (micros * clock_hz as usize / 1_000_000).max(1);
+
+        PS2Keyboard {
+            queue: VecDeque::new(),
+            clock: true,
+            data: true,
+            frame: 0,
+            step: 0,
+            countdown: 0,
+            quarter_cycles: to_cycles(QUARTER_PERIOD_MICROS),
+            gap_cycles: to_cycles(BYTE_GAP_MICROS),
+        }
+    }
+
+    fn send(&mut self, code: u16, release: bool) {
+        if code > 0xFF {
+            self.queue.push_back((code >> 8) as u8);
+        }
+        if release {
+            self.queue.push_back(0xF0);
+        }
+        self.queue.push_back(code as u8);
+    }
+
+    pub fn key_down(&mut self, code: u16) {
+        debug!("make {:04x}", code);
+        self.send(code, false);
+    }
+
+    pub fn key_up(&mut self, code: u16) {
+        debug!("break {:04x}", code);
+        self.send(code, true);
+    }
+
+    pub fn cycle(&mut self) {
+        if self.countdown > 0 {
+            self.countdown -= 1;
+            return;
+        }
+
+        if self.step == 0 {
+            let byte = match self.queue.pop_front() {
+                Some(byte) => byte,
+                None => return,
+            };
+            let parity = (byte.count_ones() % 2 == 0) as u16;
+            self.frame = ((byte as u16) << 1) | (parity << 9) | (1 << 10);
+        }
+
+        match self.step % 4 {
+            0 => self.data = (self.frame >> (self.step / 4)) & 1 == 1,
+            1 => self.clock = false,
+            3 => self.clock = true,
+            _ => {}
+        }
+
+        self.step += 1;
+        if self.step == FRAME_STEPS {
+            self.step = 0;
+            self.countdown = self.gap_cycles;
+        } else {
+            self.countdown = self.quarter_cycles - 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the frames sent, as sampled by the host on each falling clock edge
+    fn receive(kbd: &mut PS2Keyboard) -> Vec<u16> {
+        let mut frames = Vec::new();
+        let (mut frame, mut bits) = (0, 0);
+        for _ in 0..20_000 {
+            let clock = kbd.clock;
+            kbd.cycle();
+            if clock && !kbd.clock {
+                frame |= (kbd.data as u16) << bits;
+                bits += 1;
+                if bits == 11 {
+                    frames.push(frame);
+                    frame = 0;
+                    bits = 0;
+                }
+            }
+        }
+        frames
+    }
+
+    #[test]
+    fn slow_clocks_still_send_frames() {
+        let mut kbd = PS2Keyboard::new(10_000);
+        kbd.key_down(0x1C);
+        assert_eq!(receive(&mut kbd), vec![0x1C << 1 | 1 << 10]);
+    }
+
+    #[test]
+    fn frames_have_odd_parity() {
+        let mut kbd = PS2Keyboard::new(1_000_000);
+        kbd.key_down(0x1C);
+        kbd.key_up(0x1C);
+
+        let frames = receive(&mut kbd);
+        let bytes: Vec<u8> = frames.iter().map(|frame| (frame >> 1) as u8).collect();
+        assert_eq!(bytes, vec![0x1C, 0xF0, 0x1C]);
+        for frame in frames {
+            assert_eq!(frame & 1, 0, "start bit");
+            assert_eq!(frame >> 10, 1, "stop bit");
+            assert_eq!(((frame >> 1) & 0x1FF).count_ones() % 2, 1, "parity");
+        }
+    }
+}
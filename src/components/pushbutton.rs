@@ -0,0 +1,81 @@
+use log::debug;
+
+// contact chatter after a press or release: intervals between changes, and the whole bounce
+const MIN_CHATTER_MICROS: u32 = 10;
+const MAX_CHATTER_MICROS: u32 = 200;
+
+// a momentary switch to ground with a pull-up on its pin, so the pin reads low while pressed.
+// with a bounce time set, the contact chatters for that long after each press and release.
+#[derive(Debug)]
+pub struct PushButton {
+    pub pressed: bool,
+    pub output: bool,       // the pin
+    pub bounce_cycles: u32, // how long the contact chatters, or 0 for a clean switch
+    bouncing: u32,          // cycles of chatter left
+    countdown: u32,         // cycles until the contact next opens or closes while chattering
+    min_chatter: u32,
+    max_chatter: u32,
+    seed: u32, // chatter is pseudo-random, but the same on every run
+}
+
+impl PushButton {
+    pub fn new(clock_hz: u32, bounce_micros: u32, seed: u32) -> PushButton {
+        let to_cycles = |micros: u32| (micros as u64 * clock_hz as u64 / 1_000_000) as u32;
+
+        PushButton {
+            pressed: false,
+            output: true,
+            bounce_cycles: to_cycles(bounce_micros),
+            bouncing: 0,
+            countdown: 0,
+            min_chatter: to_cycles(MIN_CHATTER_MICROS).max(1),
+            max_chatter: to_cycles(MAX_CHATTER_MICROS).max(1),
+            seed,
+        }
+    }
+
+    fn set_pressed(&mut self, pressed: bool) {
+        if pressed == self.pressed {
+            return;
+        }
+        self.pressed = pressed;
+
+        if self.bounce_cycles == 0 {
+            self.output = !pressed;
+        } else {
+            self.bouncing = self.bounce_cycles;
+            self.countdown = 0;
+        }
+    }
+
+    pub fn on_press(&mut self) {
+        debug!("press");
+        self.set_pressed(true);
+    }
+
+    pub fn on_release(&mut self) {
+        debug!("release");
+        self.set_pressed(false);
+    }
+
+    fn next_chatter(&mut self) -> u32 {
+        self.seed = self.seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        self.min_chatter + (self.seed >> 16) % (self.max_chatter - self.min_chatter + 1)
+    }
+
+    pub fn cycle(&mut self) {
+        if self.bouncing == 0 {
+            return;
+        }
+
+        self.bouncing -= 1;
+        if self.bouncing == 0 {
+            self.output = !self.pressed;
+        } else if self.countdown == 0 {
+            self.output = !self.output;
+            self.countdown = self.next_chatter();
+        } else {
+            self.countdown -= 1;
+        }
+    }
+}
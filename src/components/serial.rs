@@ -0,0 +1,210 @@
+use log::{info, warn};
+use std::collections::VecDeque;
+use std::ffi::CStr;
+use std::fs::File;
+use std::io::{stdout, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::os::unix::io::FromRawFd;
+use std::sync::mpsc::{channel, sync_channel, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// bytes waiting to go out to a console before further output is dropped
+const OUTPUT_BUFFER: usize = 4096;
+
+pub trait SerialLink {
+    // byte sent by the emulated device
+    fn send(&mut self, val: u8);
+    // next byte for the emulated device, if one is waiting
+    fn recv(&mut self) -> Option<u8>;
+    // byte typed into the emulator's own terminal
+    fn key_input(&mut self, val: u8);
+    // whether the link wants keys typed into the emulator's own terminal
+    fn uses_terminal(&self) -> bool;
+}
+
+// Bridges a serial device to the emulator's stdin/stdout.  Input arrives through key_input
+// because the debugger owns the terminal while the emulation is running.
+pub struct HostTerminal {
+    input: VecDeque<u8>,
+}
+
+impl HostTerminal {
+    pub fn new() -> HostTerminal {
+        HostTerminal {
+            input: VecDeque::new(),
+        }
+    }
+}
+
+impl SerialLink for HostTerminal {
+    fn send(&mut self, val: u8) {
+        let mut stdout = stdout();
+        stdout.write_all(&[val]).unwrap();
+        stdout.flush().unwrap();
+    }
+
+    fn recv(&mut self) -> Option<u8> {
+        self.input.pop_front()
+    }
+
+    fn key_input(&mut self, val: u8) {
+        self.input.push_back(val);
+    }
+
+    fn uses_terminal(&self) -> bool {
+        true
+    }
+}
+
+// Console I/O happens on background threads so that the emulation never blocks on a slow or
+// missing client.  Output is dropped when nobody is reading.
+pub struct ThreadedConsole {
+    output: SyncSender<u8>,
+    input: Receiver<u8>,
+}
+
+impl ThreadedConsole {
+    // serves the console to one client at a time on a localhost TCP port
+    pub fn listen_tcp(port: u16) -> ThreadedConsole {
+        let listener = TcpListener::bind(("127.0.0.1", port)).unwrap();
+
+        let client: Arc<Mutex<Option<TcpStream>>> = Arc::new(Mutex::new(None));
+        let (input_send, input_recv) = channel();
+        let (output_send, output_recv) = sync_channel::<u8>(OUTPUT_BUFFER);
+
+        let writer_client = client.clone();
+        thread::spawn(move || {
+            for val in output_recv.iter() {
+                let mut client = writer_client.lock().unwrap();
+                if let Some(stream) = client.as_mut() {
+                    if stream.write_all(&[val]).is_err() {
+                        *client = None;
+                    }
+                }
+            }
+        });
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = match stream {
+                    Ok(s) => s,
+                    Err(e) => {
+                        warn!("serial console accept failed: {}", e);
+                        continue;
+                    }
+                };
+
+                info!("serial console client connected: {:?}", stream.peer_addr());
+                *client.lock().unwrap() = Some(stream.try_clone().unwrap());
+
+                let mut buf = [0u8; 256];
+                loop {
+                    match stream.read(&mut buf) {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => {
+                            for val in buf[..n].iter() {
+                                if input_send.send(*val).is_err() {
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                }
+
+                info!("serial console client disconnected");
+                *client.lock().unwrap() = None;
+            }
+        });
+
+        ThreadedConsole {
+            output: output_send,
+            input: input_recv,
+        }
+    }
+
+    // creates a pseudo-terminal and returns the console with the path of its slave device
+    pub fn open_pty() -> (ThreadedConsole, String) {
+        let (master, path) = unsafe {
+            let fd = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY);
+            if fd < 0 || libc::grantpt(fd) != 0 || libc::unlockpt(fd) != 0 {
+                panic!("failed to create pty: {}", std::io::Error::last_os_error());
+            }
+
+            let mut name = [0 as libc::c_char; 128];
+            if libc::ptsname_r(fd, name.as_mut_ptr(), name.len()) != 0 {
+                panic!("failed to name pty: {}", std::io::Error::last_os_error());
+            }
+            let path = CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned();
+
+            (File::from_raw_fd(fd), path)
+        };
+
+        // hold the slave open so the master doesn't hang up between clients, and put it in raw
+        // mode so the line discipline doesn't echo or translate anything
+        let slave = File::options().read(true).write(true).open(&path).unwrap();
+        unsafe {
+            use std::os::unix::io::AsRawFd;
+            let mut termios: libc::termios = std::mem::zeroed();
+            libc::tcgetattr(slave.as_raw_fd(), &mut termios);
+            libc::cfmakeraw(&mut termios);
+            libc::tcsetattr(slave.as_raw_fd(), libc::TCSANOW, &termios);
+        }
+
+        let (input_send, input_recv) = channel();
+        let (output_send, output_recv) = sync_channel::<u8>(OUTPUT_BUFFER);
+
+        let mut writer = master.try_clone().unwrap();
+        thread::spawn(move || {
+            for val in output_recv.iter() {
+                if writer.write_all(&[val]).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut reader = master;
+        thread::spawn(move || {
+            let _slave = slave;
+            let mut buf = [0u8; 256];
+            loop {
+                match reader.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => {
+                        for val in buf[..n].iter() {
+                            if input_send.send(*val).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        (
+            ThreadedConsole {
+                output: output_send,
+                input: input_recv,
+            },
+            path,
+        )
+    }
+}
+
+impl SerialLink for ThreadedConsole {
+    fn send(&mut self, val: u8) {
+        // drop output rather than stall when the console isn't being read
+        let _ = self.output.try_send(val);
+    }
+
+    fn recv(&mut self) -> Option<u8> {
+        self.input.try_recv().ok()
+    }
+
+    fn key_input(&mut self, _val: u8) {
+    }
+
+    fn uses_terminal(&self) -> bool {
+        false
+    }
+}
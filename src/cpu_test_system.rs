@@ -17,6 +17,10 @@ impl System for CPUTestSystem {
     type BusType = SystemBus;
     type PortsType = NullPorts;
 
+    fn get_clock_hz(&self) -> u32 {
+        1_000_000
+    }
+
     fn is_halted(&self) -> bool {
         self.cpu.is_halted()
     }
@@ -29,22 +33,58 @@ impl System for CPUTestSystem {
         None
     }
 
+    fn get_graphic_display(&mut self) -> Option<&mut KS0108> {
+        None
+    }
+
     fn get_ram(&self) -> &RAM {
         &self.cpu.bus.ram
     }
 
-    fn get_controller(&mut self) -> Option<&mut SNESController> {
+    fn get_controller(&mut self, _port: usize) -> Option<&mut dyn Controller> {
         None
     }
 
-    fn get_peripheral_controller(&self) -> Option<&W65C22<Self::PortsType>> {
+    fn get_keyboard(&mut self) -> Option<&mut PS2Keyboard> {
         None
     }
 
+    fn get_keypad(&mut self) -> Option<&mut MatrixKeypad> {
+        None
+    }
+
+    fn get_push_button(&mut self, _n: usize) -> Option<&mut PushButton> {
+        None
+    }
+
+    fn get_lights(&mut self) -> Vec<&mut Lights> {
+        Vec::new()
+    }
+
+    fn get_serial(&mut self) -> Option<&mut W65C51> {
+        None
+    }
+
+    fn peek_serial(&self) -> Option<&W65C51> {
+        None
+    }
+
+    fn get_peripheral_controllers(&self) -> Vec<(&str, &W65C22<Self::PortsType>)> {
+        Vec::new()
+    }
+
+    fn get_interface_adapters(&self) -> Vec<(&str, &MOS6526<Self::PortsType>)> {
+        Vec::new()
+    }
+
     fn peek(&mut self, addr: u16) -> u8 {
         self.cpu.bus.peek(addr)
     }
 
+    fn reset(&mut self) {
+        self.cpu.reset();
+    }
+
     fn cycle(&mut self) {
         self.cpu.cycle();
     }
@@ -86,4 +126,6 @@ impl Ports for NullPorts {
     fn peek(&self, _port: Port) -> u8 { unimplemented!(); }
     fn read(&mut self, _port: Port) -> u8 { unimplemented!();}
     fn write(&mut self, _port: Port, _val: u8) {unimplemented!();}
+    fn peek_control(&self, _line: ControlLine) -> bool { unimplemented!(); }
+    fn write_control(&mut self, _line: ControlLine, _val: bool) { unimplemented!(); }
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use chrono::Duration;
-use crossterm::{cursor, event, execute, style::Print, terminal};
+use crossterm::{cursor, event, execute, style::{Attribute, Print}, terminal};
+use crossterm::event::{KeyEventKind, KeyModifiers, KeyboardEnhancementFlags};
 use std::collections::HashMap;
-use std::io::{stdout, Write};
+use std::io::stdout;
 use std::sync::mpsc::channel;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
@@ -12,11 +13,24 @@ use std::time::Instant;
 use timer::Timer;
 
 use crate::components::*;
-use crate::system::System;
+use crate::components::ps2;
+use crate::input::Switch;
+use crate::keymap::{KeyAction, KeyMap};
+use crate::movie::{InputEvent, Movie};
+use crate::screenshot;
+use crate::system::{cycles_to_nanos, System};
+use crate::transcript::Transcript;
+use crate::vcd::{Capture, Signal};
 
-const CYCLE_NANOSECONDS: u64 = 1000;
 const CYCLES_PER_EPOCH: u64 = 10000;
 const WINDOW_SIZE: u64 = 200;
+// how long a keypress holds a button down, when the terminal can't report key releases
+const DEFAULT_HOLD_MS: u64 = 100;
+// limits on the run speed set from the keyboard, as nanoseconds per cycle
+const MIN_CYCLE_NANOSECONDS: u64 = 125;
+const MAX_CYCLE_NANOSECONDS: u64 = 1_024_000;
+// how often the run view looks for changes to the board's lights
+const LIGHTS_CHECK_CYCLES: u64 = 1000;
 
 pub struct Debugger<SystemType: System> {
     pub sys: SystemType,
@@ -29,10 +43,22 @@ pub struct Debugger<SystemType: System> {
     pub timer: Timer,
     pub cycle_gate: Arc<(Condvar, Mutex<u32>)>,
     pub bench: bool,
+    pub screenshot_path: Option<String>, // written at the end of each headless run
+    pub transcript: Transcript,
+    pub hold_cycles: u64,
+    pub holds: Vec<(Switch, Option<u64>)>, // held switches, and the cycle at which each is let go
+    pub key_releases: Option<bool>,                // whether the terminal reports key releases
+    pub keys: KeyMap,
+    pub clock_hz: u32,    // the board's clock, which emulated time is counted in
+    pub cycle_nanos: u64, // real time given to each cycle, when not benchmarking
+    pub movie: Movie,
+    pub movie_path: Option<String>, // the movie is written here whenever a run stops
+    pub vcd: Capture,
 }
 
 impl<SystemType: System> Debugger<SystemType> {
     pub fn new(sys: SystemType) -> Debugger<SystemType> {
+        let clock_hz = sys.get_clock_hz();
         Debugger {
             sys: sys,
             breakpoints: Vec::new(),
@@ -40,10 +66,21 @@ impl<SystemType: System> Debugger<SystemType> {
             addr2sym: HashMap::new(),
             cycle_count: 0,
             epoch_start: Instant::now(),
-            avg_nanos_per_epoch: CYCLES_PER_EPOCH * WINDOW_SIZE * CYCLE_NANOSECONDS,
+            avg_nanos_per_epoch: cycles_to_nanos(CYCLES_PER_EPOCH * WINDOW_SIZE, clock_hz),
             timer: Timer::new(),
             cycle_gate: Arc::new((Condvar::new(), Mutex::new(0))),
             bench: false,
+            screenshot_path: None,
+            transcript: Transcript::new(clock_hz),
+            hold_cycles: DEFAULT_HOLD_MS * clock_hz as u64 / 1000,
+            holds: Vec::new(),
+            key_releases: None,
+            keys: KeyMap::default(),
+            clock_hz,
+            cycle_nanos: cycles_to_nanos(1, clock_hz),
+            movie: Movie::new(),
+            movie_path: None,
+            vcd: Capture::new(clock_hz),
         }
     }
 
@@ -103,10 +140,24 @@ impl<SystemType: System> Debugger<SystemType> {
                 if self.breakpoints.contains(&(self.sys.get_cpu().pc - 1)) {
                     break;
                 }
+
+                if let Some(message) = self.take_display_violation() {
+                    println!("LCD: {}", message);
+                    break;
+                }
+
+                if self.movie.reached_end(self.cycle_count) {
+                    break;
+                }
             }
         }
 
         drop(cycle_schedule);
+        self.save_movie_path();
+
+        if let Some(path) = self.screenshot_path.clone() {
+            self.screenshot(path.as_str());
+        }
     }
 
     pub fn bench(&mut self) {
@@ -123,7 +174,19 @@ impl<SystemType: System> Debugger<SystemType> {
 
     pub fn run_n(&mut self, mut skip_breakpoints: u32) {
         let mut stdout = stdout();
-        let cycle_schedule = self.start_timer();
+        let mut cycle_schedule = self.start_timer();
+
+        // asking the terminal takes a round trip, so only do it once
+        let key_releases = *self
+            .key_releases
+            .get_or_insert_with(|| terminal::supports_keyboard_enhancement().unwrap_or(false));
+        if key_releases {
+            execute!(
+                stdout,
+                event::PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)
+            )
+            .unwrap();
+        }
 
         let (key_send, key_recv) = channel();
         let key_exit = Arc::new(AtomicBool::new(false));
@@ -151,47 +214,90 @@ impl<SystemType: System> Debugger<SystemType> {
 
         execute!(stdout, cursor::Hide).unwrap();
 
-        {
-            if let Some(dsp) = self.sys.get_display() {
-                let (line1, line2) = dsp.get_output();
-                execute!(
-                    stdout,
-                    Print(format!(
-                        "┌────────────────┐\r\n│{}|\r\n|{}|\r\n└────────────────┘\r\n>\r",
-                        line1, line2
-                    )),
-                    cursor::MoveUp(3)
-                )
-                .unwrap();
-            }
+        let lines = self.get_screen_lines();
+        let has_screen = !lines.is_empty();
+
+        // lines from the top of the screen to the status line
+        let dsp_height = if has_screen { lines.len() as u16 } else { 3 };
+
+        if has_screen {
+            execute!(stdout, Print(format!("{}>\r", format_lines(&lines))), cursor::MoveUp(dsp_height)).unwrap();
         }
 
+        let mut violation = None;
+        let mut notice = String::new();
+        let mut redraw = false;
+        let mut next_lights_check = 0;
         self.epoch_start = Instant::now();
         loop {
             self.step_next();
+            self.release_expired_holds();
+
+            if self.cycle_count >= next_lights_check {
+                next_lights_check = self.cycle_count + LIGHTS_CHECK_CYCLES;
+                redraw |= self.take_lights_updated();
+            }
 
             if let Ok(event) = key_recv.try_recv() {
                 match event {
+                    event::Event::Key(key_event) if key_event.kind == KeyEventKind::Release => {
+                        let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+                        if !ctrl && self.keyboard_takes_keys() {
+                            self.release_keyboard_keys(key_event.code);
+                        } else if let event::KeyCode::Char(c) = key_event.code {
+                            if let Some(KeyAction::Press(switch)) = self.get_key_action(c, ctrl) {
+                                self.release_switch(switch);
+                            }
+                        }
+                    }
                     event::Event::Key(key_event) => match key_event.code {
                         event::KeyCode::Esc => {
                             break;
                         }
                         event::KeyCode::Char(c) => {
-                            if let Some(con) = self.sys.get_controller() {
-                                match c {
-                                    'w' => { con.on_press(Button::Up); con.on_release(Button::Up); }
-                                    's' => { con.on_press(Button::Down); con.on_release(Button::Down); }
-                                    'a' => { con.on_press(Button::Left); con.on_release(Button::Left); }
-                                    'd' => { con.on_press(Button::Right); con.on_release(Button::Right); }
-                                    'j' => { con.on_press(Button::A); con.on_release(Button::A); }
-                                    'k' => { con.on_press(Button::B); con.on_release(Button::B); }
-                                    'l' => { con.on_press(Button::Select); con.on_release(Button::Select); }
-                                    ';' => { con.on_press(Button::Start); con.on_release(Button::Start); }
-                                    _ => {}
+                            // Ctrl takes a key past the board to the key map
+                            let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+                            let action = if ctrl || !self.type_key(key_event.code, key_releases) {
+                                self.get_key_action(c, ctrl)
+                            } else {
+                                None
+                            };
+                            if let Some(action) = action {
+                                match action {
+                                    // while a movie plays, it is the only source of input
+                                    KeyAction::Press(..) | KeyAction::Reset if self.movie.playing => {}
+                                    KeyAction::Press(switch) => self.hold_switch(switch, key_releases),
+                                    KeyAction::Pause => break,
+                                    KeyAction::Reset => {
+                                        self.input(InputEvent::Reset);
+                                        notice = "reset".to_string();
+                                    }
+                                    KeyAction::Screenshot => {
+                                        let path = self
+                                            .screenshot_path
+                                            .clone()
+                                            .unwrap_or_else(|| format!("screenshot-{}.png", self.cycle_count));
+                                        notice = match self.write_screenshot(&path) {
+                                            Ok(()) => format!("wrote {}", path),
+                                            Err(e) => e,
+                                        };
+                                    }
+                                    KeyAction::Faster | KeyAction::Slower => {
+                                        self.cycle_nanos = if action == KeyAction::Faster {
+                                            (self.cycle_nanos / 2).max(MIN_CYCLE_NANOSECONDS)
+                                        } else {
+                                            (self.cycle_nanos * 2).min(MAX_CYCLE_NANOSECONDS)
+                                        };
+                                        cycle_schedule = self.start_timer();
+                                        notice = format!("target {:.3}MHz", 1000.0 / self.cycle_nanos as f32);
+                                    }
                                 }
+                                redraw = true;
                             }
                         }
-                        _ => {}
+                        code => {
+                            self.type_key(code, key_releases);
+                        }
                     },
                     _ => {}
                 }
@@ -201,6 +307,10 @@ impl<SystemType: System> Debugger<SystemType> {
                 break;
             }
 
+            if self.movie.reached_end(self.cycle_count) {
+                break;
+            }
+
             if self.breakpoints.contains(&(self.sys.get_cpu().pc - 1)) {
                 if skip_breakpoints == 0 {
                     break;
@@ -209,36 +319,44 @@ impl<SystemType: System> Debugger<SystemType> {
                 }
             }
 
-            if let Some(dsp) = self.sys.get_display() {
-                if dsp.get_updated() || fps_refresh.load(Ordering::Acquire) {
+            if let Some(message) = self.take_display_violation() {
+                violation = Some(message);
+                break;
+            }
+
+            let nanos_per_cycle = self.avg_nanos_per_epoch / (CYCLES_PER_EPOCH * WINDOW_SIZE);
+            if has_screen {
+                if self.take_screen_updated() || fps_refresh.load(Ordering::Acquire) || redraw {
                     fps_refresh.store(false, Ordering::Release);
-                    let (line1, line2) = dsp.get_output();
+                    redraw = false;
+                    let lines = self.get_screen_lines();
                     execute!(
                         stdout,
                         Print(format!(
-                            "│{}│\r\n│{}│\r\n\n> {:2.2?}MHz ({:?}ns)",
-                            line1,
-                            line2,
-                            1000.0
-                                / (self.avg_nanos_per_epoch / (CYCLES_PER_EPOCH * WINDOW_SIZE)) as f32,
-                            self.avg_nanos_per_epoch / (CYCLES_PER_EPOCH * WINDOW_SIZE)
+                            "{}> {:2.2?}MHz ({:?}ns)  {}",
+                            format_lines(&lines),
+                            1000.0 / nanos_per_cycle as f32,
+                            nanos_per_cycle,
+                            notice
                         )),
                         terminal::Clear(crossterm::terminal::ClearType::UntilNewLine),
                         cursor::MoveToColumn(0),
-                        cursor::MoveUp(3)
+                        cursor::MoveUp(dsp_height)
                     )
                     .unwrap();
                 }
             } else {
-                if fps_refresh.load(Ordering::Acquire) {
+                if fps_refresh.load(Ordering::Acquire) || redraw {
                     fps_refresh.store(false, Ordering::Release);
+                    redraw = false;
                     execute!(
                         stdout,
                         Print(format!(
-                            "\n> {:2.2?}MHz ({:?}ns)",
+                            "\n> {:2.2?}MHz ({:?}ns)  {}",
                             1000.0
                                 / (self.avg_nanos_per_epoch / (CYCLES_PER_EPOCH * WINDOW_SIZE)) as f32,
-                            self.avg_nanos_per_epoch / (CYCLES_PER_EPOCH * WINDOW_SIZE)
+                            self.avg_nanos_per_epoch / (CYCLES_PER_EPOCH * WINDOW_SIZE),
+                            notice
                         )),
                         terminal::Clear(crossterm::terminal::ClearType::UntilNewLine),
                         cursor::MoveToColumn(0)
@@ -248,13 +366,172 @@ impl<SystemType: System> Debugger<SystemType> {
             }
         }
 
-        execute!(stdout, cursor::Show, cursor::MoveDown(3)).unwrap();
+        execute!(stdout, cursor::Show, cursor::MoveDown(dsp_height)).unwrap();
+        if key_releases {
+            execute!(stdout, event::PopKeyboardEnhancementFlags).unwrap();
+        }
+        if let Some(message) = violation {
+            print!("\r\nLCD: {}\r\n", message);
+        }
+
+        // nothing is holding the switches down once the emulation stops
+        while let Some((switch, _)) = self.holds.last().cloned() {
+            self.release_switch(switch);
+        }
+
+        self.save_movie_path();
 
         drop(cycle_schedule);
         drop(fps_schedule);
         key_exit.store(true, Ordering::Release)
     }
 
+    pub fn set_hold_ms(&mut self, ms: u64) {
+        self.hold_cycles = ms * self.clock_hz as u64 / 1000;
+    }
+
+    // press a switch until its key is released or, failing that, for hold_cycles.  key repeats
+    // extend the hold.
+    fn hold_switch(&mut self, switch: Switch, until_released: bool) {
+        let until = if until_released {
+            None
+        } else {
+            Some(self.cycle_count + self.hold_cycles)
+        };
+
+        match self.holds.iter_mut().find(|(s, _)| *s == switch) {
+            Some(hold) => hold.1 = until,
+            None => {
+                self.input(InputEvent::Press(switch));
+                self.holds.push((switch, until));
+            }
+        }
+    }
+
+    fn release_switch(&mut self, switch: Switch) {
+        self.holds.retain(|(s, _)| *s != switch);
+        self.input(InputEvent::Release(switch));
+    }
+
+    // terminals report Ctrl with a shifted letter as the lower case letter
+    fn get_key_action(&self, key: char, ctrl: bool) -> Option<KeyAction> {
+        match self.keys.get(key) {
+            None if ctrl => self.keys.get(key.to_ascii_uppercase()),
+            action => action,
+        }
+    }
+
+    // a serial console on this terminal takes all typed keys, and a keyboard on the board takes
+    // them next.  returns whether either took the key.
+    fn type_key(&mut self, code: event::KeyCode, until_released: bool) -> bool {
+        if let Some(acia) = self.sys.get_serial().filter(|a| a.link.uses_terminal()) {
+            let mut buf = [0u8; 4];
+            let bytes: &[u8] = match code {
+                event::KeyCode::Char(c) => c.encode_utf8(&mut buf).as_bytes(),
+                event::KeyCode::Enter => b"\r",
+                event::KeyCode::Backspace => &[0x08],
+                _ => &[],
+            };
+            for &b in bytes {
+                acia.link.key_input(b);
+            }
+            true
+        } else if self.keyboard_takes_keys() {
+            self.press_keyboard_keys(code, until_released);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn keyboard_takes_keys(&mut self) -> bool {
+        self.sys.get_serial().filter(|a| a.link.uses_terminal()).is_none() && self.sys.get_keyboard().is_some()
+    }
+
+    // types a key on the board's keyboard, releasing it straight away unless the terminal will
+    // report its release
+    fn press_keyboard_keys(&mut self, code: event::KeyCode, until_released: bool) {
+        if self.movie.playing || self.sys.get_keyboard().is_none() {
+            return;
+        }
+
+        for key in get_keyboard_keys(code) {
+            self.input(InputEvent::KeyDown(key));
+        }
+        if !until_released {
+            self.release_keyboard_keys(code);
+        }
+    }
+
+    fn release_keyboard_keys(&mut self, code: event::KeyCode) {
+        if self.movie.playing {
+            return;
+        }
+
+        for key in get_keyboard_keys(code).into_iter().rev() {
+            self.input(InputEvent::KeyUp(key));
+        }
+    }
+
+    // applies input from the keyboard, adding it to the movie when recording
+    fn input(&mut self, event: InputEvent) {
+        self.movie.record(self.cycle_count, event);
+        self.apply_input(event);
+    }
+
+    fn apply_input(&mut self, event: InputEvent) {
+        match event {
+            InputEvent::Press(Switch::Controller(port, btn)) => {
+                if let Some(con) = self.sys.get_controller(port) {
+                    con.on_press(btn);
+                }
+            }
+            InputEvent::Release(Switch::Controller(port, btn)) => {
+                if let Some(con) = self.sys.get_controller(port) {
+                    con.on_release(btn);
+                }
+            }
+            InputEvent::Press(Switch::Keypad(label)) => {
+                if let Some(keypad) = self.sys.get_keypad() {
+                    keypad.on_press(label);
+                }
+            }
+            InputEvent::Release(Switch::Keypad(label)) => {
+                if let Some(keypad) = self.sys.get_keypad() {
+                    keypad.on_release(label);
+                }
+            }
+            InputEvent::Press(Switch::PushButton(n)) => {
+                if let Some(btn) = self.sys.get_push_button(n) {
+                    btn.on_press();
+                }
+            }
+            InputEvent::Release(Switch::PushButton(n)) => {
+                if let Some(btn) = self.sys.get_push_button(n) {
+                    btn.on_release();
+                }
+            }
+            InputEvent::KeyDown(code) => {
+                if let Some(kbd) = self.sys.get_keyboard() {
+                    kbd.key_down(code);
+                }
+            }
+            InputEvent::KeyUp(code) => {
+                if let Some(kbd) = self.sys.get_keyboard() {
+                    kbd.key_up(code);
+                }
+            }
+            InputEvent::Reset => self.sys.reset(),
+        }
+    }
+
+    fn release_expired_holds(&mut self) {
+        let now = self.cycle_count;
+        while let Some(&(switch, _)) = self.holds.iter().find(|(_, until)| matches!(until, Some(u) if *u <= now)) {
+            self.release_switch(switch);
+        }
+    }
+
     pub fn run(&mut self) {
         self.run_n(1);
     }
@@ -323,20 +600,187 @@ impl<SystemType: System> Debugger<SystemType> {
     }
 
     pub fn show_dsp(&mut self) {
+        for line in self.get_screen_lines() {
+            println!("{}", line);
+        }
+
         if let Some(dsp) = self.sys.get_display() {
-            let (line1, line2) = dsp.get_output();
+            if dsp.busy_writes > 0 {
+                println!("writes while busy: {}", dsp.busy_writes);
+            }
+        }
+    }
+
+    pub fn screenshot(&mut self, path: &str) {
+        match self.write_screenshot(path) {
+            Ok(()) => println!("wrote {}", path),
+            Err(e) => println!("{}", e),
+        }
+    }
+
+    fn write_screenshot(&mut self, path: &str) -> Result<(), String> {
+        let img = if let Some(dsp) = self.sys.get_display() {
+            screenshot::render_hd44780(dsp)
+        } else if let Some(glcd) = self.sys.get_graphic_display() {
+            screenshot::render_ks0108(glcd)
+        } else {
+            return Err("no display".to_string());
+        };
+
+        img.write_png(path).map_err(|e| format!("screenshot failed: {}", e))
+    }
+
+    // events are replayed by cycles since power-on, and input before a recording started would
+    // be missing from it, so a movie can only be recorded from the first cycle
+    pub fn start_movie(&mut self) {
+        if self.cycle_count > 0 {
+            println!("movies are recorded from power-on: use --record");
+            return;
+        }
+        self.movie.start();
+    }
+
+    pub fn stop_movie(&mut self) {
+        self.movie.stop();
+    }
+
+    pub fn clear_movie(&mut self) {
+        self.movie.clear();
+    }
+
+    pub fn show_movie(&self) {
+        print!("{}", self.movie.format(self.cycle_count));
+        if self.movie.recording {
+            println!("(recording)");
+        }
+        if self.movie.playing {
+            println!("(playing)");
+        }
+    }
+
+    pub fn save_movie(&self, path: &str) {
+        match self.movie.save(path, self.cycle_count) {
+            Ok(()) => println!("wrote {}", path),
+            Err(e) => println!("movie failed: {}", e),
+        }
+    }
 
-            println!("┌────────────────┐");
-            println!("│{}│", line1);
-            println!("│{}│", line2);
-            println!("└────────────────┘");
+    fn save_movie_path(&self) {
+        if let Some(path) = &self.movie_path {
+            if let Err(e) = self.movie.save(path, self.cycle_count) {
+                print!("\r\nmovie failed: {}\r\n", e);
+            }
+        }
+    }
+
+    pub fn show_keys(&self) {
+        print!("{}", self.keys.format());
+    }
+
+    pub fn add_vcd_signal(&mut self, spec: &str) {
+        let signals = match Signal::parse(spec, &self.sym2addr) {
+            Some(signals) => signals,
+            None => {
+                println!("invalid signal: '{}'", spec);
+                return;
+            }
+        };
+
+        for signal in signals {
+            if let Signal::Pin(chip, _) | Signal::Port(chip, _) = &signal {
+                if !get_chip_ports(&self.sys).iter().any(|(name, _)| name == chip) {
+                    println!("unknown peripheral controller: '{}'", chip);
+                    return;
+                }
+            }
+            if !self.vcd.add(signal.clone()) {
+                println!("already capturing {}", signal);
+            }
+        }
+    }
+
+    pub fn remove_vcd_signal(&mut self, spec: &str) {
+        match Signal::parse(spec, &self.sym2addr) {
+            Some(signals) => {
+                for signal in signals {
+                    if !self.vcd.remove(&signal) {
+                        println!("not capturing {}", signal);
+                    }
+                }
+            }
+            None => println!("invalid signal: '{}'", spec),
+        }
+    }
+
+    pub fn start_vcd(&mut self) {
+        if self.vcd.signals.is_empty() {
+            println!("no signals to capture: add some with 'vcd add <signal>'");
+            return;
+        }
+        self.vcd.start();
+    }
+
+    pub fn stop_vcd(&mut self) {
+        self.vcd.stop();
+    }
+
+    pub fn clear_vcd(&mut self) {
+        self.vcd.clear();
+    }
+
+    pub fn show_vcd(&self) {
+        for signal in self.vcd.signals.iter() {
+            println!("{}", signal);
+        }
+        match (self.vcd.changes.first(), self.vcd.changes.last()) {
+            (Some((first, ..)), Some((last, ..))) => {
+                println!("{} changes, cycles {} to {}", self.vcd.changes.len(), first, last)
+            }
+            _ => println!("no changes"),
+        }
+        if self.vcd.recording {
+            println!("(recording)");
+        }
+    }
+
+    pub fn save_vcd(&self, path: &str) {
+        match self.vcd.save(path) {
+            Ok(()) => println!("wrote {}", path),
+            Err(e) => println!("vcd failed: {}", e),
+        }
+    }
+
+    pub fn start_transcript(&mut self) {
+        self.transcript.start();
+    }
+
+    pub fn stop_transcript(&mut self) {
+        self.transcript.stop();
+    }
+
+    pub fn clear_transcript(&mut self) {
+        self.transcript.clear();
+    }
+
+    pub fn show_transcript(&self) {
+        print!("{}", self.transcript.format());
+        if self.transcript.recording {
+            println!("(recording)");
+        }
+    }
+
+    pub fn save_transcript(&self, path: &str) {
+        match self.transcript.save(path) {
+            Ok(()) => println!("wrote {}", path),
+            Err(e) => println!("transcript failed: {}", e),
         }
     }
 
     pub fn show_per(&self) {
-        if let Some(per) = self.sys.get_peripheral_controller() {
+        for (name, per) in self.sys.get_peripheral_controllers() {
             println!(
-                "PA:{:02x}[{:02x}]  PB:{:02x}[{:02x}]  T1:{:04x}/{:04x}  I:{:02x}[{:02x}]",
+                "{}  PA:{:02x}[{:02x}]  PB:{:02x}[{:02x}]  T1:{:04x}/{:04x}  I:{:02x}[{:02x}]",
+                name,
                 per.ora,
                 per.ddra,
                 per.orb,
@@ -347,6 +791,71 @@ impl<SystemType: System> Debugger<SystemType> {
                 per.ier
             );
         }
+
+        for (name, cia) in self.sys.get_interface_adapters() {
+            println!(
+                "{}  PA:{:02x}[{:02x}]  PB:{:02x}[{:02x}]  TA:{:04x}/{:04x}  TB:{:04x}/{:04x}  TOD:{:02x}:{:02x}:{:02x}.{:x}  I:{:02x}[{:02x}]",
+                name,
+                cia.pra,
+                cia.ddra,
+                cia.prb,
+                cia.ddrb,
+                cia.ta.counter,
+                cia.ta.latch,
+                cia.tb.counter,
+                cia.tb.latch,
+                cia.tod[3],
+                cia.tod[2],
+                cia.tod[1],
+                cia.tod[0],
+                cia.icr,
+                cia.imr
+            );
+        }
+
+        if let Some(acia) = self.sys.peek_serial() {
+            println!(
+                "ACIA  RX:{:02x}  TX:{:02x}  ST:{:02x}  CMD:{:02x}  CTL:{:02x}  ({} cycles/byte)",
+                acia.rdr,
+                acia.tdr,
+                acia.status,
+                acia.command,
+                acia.control,
+                acia.byte_cycles()
+            );
+        }
+    }
+
+    pub fn show_per_detail(&self, name: &str) {
+        match self.sys.get_peripheral_controllers().into_iter().find(|(n, _)| *n == name) {
+            Some((_, per)) => {
+                println!("ORA:{:02x}  DDRA:{:02x}  IRA:{:02x}  CA1:{}", per.ora, per.ddra, per.ira, per.ca1 as u8);
+                println!("ORB:{:02x}  DDRB:{:02x}  IRB:{:02x}  CB1:{}", per.orb, per.ddrb, per.irb, per.cb1 as u8);
+                println!("T1C:{:04x}  T1L:{:04x}  T2C:{:04x}  SR:{:02x}", per.t1c, per.t1l, per.t2c, per.sr);
+                println!("ACR:{:02x}  PCR:{:02x}  IFR:{:02x}  IER:{:02x}", per.acr, per.pcr, per.ifr.get(), per.ier);
+                for line in per.ports.describe_pins() {
+                    println!("{}", line);
+                }
+            }
+            None => match self.sys.get_interface_adapters().into_iter().find(|(n, _)| *n == name) {
+                Some((_, cia)) => {
+                    println!("PRA:{:02x}  DDRA:{:02x}  CRA:{:02x}  TA:{:04x}/{:04x}", cia.pra, cia.ddra, cia.cra, cia.ta.counter, cia.ta.latch);
+                    println!("PRB:{:02x}  DDRB:{:02x}  CRB:{:02x}  TB:{:04x}/{:04x}", cia.prb, cia.ddrb, cia.crb, cia.tb.counter, cia.tb.latch);
+                    println!(
+                        "TOD:{:02x}:{:02x}:{:02x}.{:x}  ALARM:{:02x}:{:02x}:{:02x}.{:x}  SDR:{:02x}  ICR:{:02x}  IMR:{:02x}",
+                        cia.tod[3], cia.tod[2], cia.tod[1], cia.tod[0],
+                        cia.tod_alarm[3], cia.tod_alarm[2], cia.tod_alarm[1], cia.tod_alarm[0],
+                        cia.sdr, cia.icr, cia.imr
+                    );
+                    for line in cia.ports.describe_pins() {
+                        println!("{}", line);
+                    }
+                }
+                None => {
+                    println!("unknown peripheral controller: '{}'", name);
+                }
+            },
+        }
     }
 
     fn start_timer(&mut self) -> timer::Guard {
@@ -354,13 +863,68 @@ impl<SystemType: System> Debugger<SystemType> {
 
         let cycle_gate = self.cycle_gate.clone();
         self.timer
-            .schedule_repeating(Duration::nanoseconds(CYCLE_NANOSECONDS as i64), move || {
+            .schedule_repeating(Duration::nanoseconds(self.cycle_nanos as i64), move || {
                 let (cond, mutex) = &*cycle_gate.clone();
                 *mutex.lock().unwrap() += 1;
                 cond.notify_all();
             })
     }
 
+    // the board's display as shown in the terminal: its width in characters, and its rows
+    fn get_screen(&mut self) -> Option<(usize, Vec<String>)> {
+        if let Some(dsp) = self.sys.get_display() {
+            return Some((dsp.geometry.columns as usize, get_display_rows(dsp)));
+        }
+
+        self.sys.get_graphic_display().map(|glcd| (glcd::WIDTH / 2, glcd.get_output()))
+    }
+
+    // the display in its box, with the board's lights beside it
+    fn get_screen_lines(&mut self) -> Vec<String> {
+        let (mut lines, width) = match self.get_screen() {
+            Some((width, rows)) => {
+                let mut lines = vec![format!("┌{}┐", "─".repeat(width))];
+                lines.extend(rows.iter().map(|row| format!("│{}│", row)));
+                lines.push(format!("└{}┘", "─".repeat(width)));
+                (lines, width + 2)
+            }
+            None => (Vec::new(), 0),
+        };
+
+        let panel: Vec<String> = self.sys.get_lights().into_iter().flat_map(|lights| lights.format()).collect();
+        for (ix, row) in panel.iter().enumerate() {
+            if ix == lines.len() {
+                lines.push(" ".repeat(width));
+            }
+            lines[ix] = format!("{}  {}", lines[ix], row);
+        }
+        lines
+    }
+
+    fn take_lights_updated(&mut self) -> bool {
+        // every group's flag is taken, even once one has been found
+        let mut updated = false;
+        for lights in self.sys.get_lights() {
+            updated |= lights.get_updated();
+        }
+        updated
+    }
+
+    fn take_screen_updated(&mut self) -> bool {
+        if let Some(dsp) = self.sys.get_display() {
+            dsp.get_updated()
+        } else if let Some(glcd) = self.sys.get_graphic_display() {
+            glcd.get_updated()
+        } else {
+            false
+        }
+    }
+
+    // a display write made while busy that should stop the emulation
+    fn take_display_violation(&mut self) -> Option<String> {
+        self.sys.get_display().and_then(|dsp| dsp.busy_violation.take())
+    }
+
     fn step_next(&mut self) {
         self.cycle();
         while self.sys.get_cpu().tcu != 1 {
@@ -369,6 +933,13 @@ impl<SystemType: System> Debugger<SystemType> {
     }
 
     fn cycle(&mut self) {
+        while self.movie.playing {
+            match self.movie.next_due(self.cycle_count) {
+                Some(event) => self.apply_input(event),
+                None => break,
+            }
+        }
+
         self.cycle_count = self.cycle_count.wrapping_add(1);
         if self.cycle_count % CYCLES_PER_EPOCH == 0 {
             let now = Instant::now();
@@ -380,7 +951,7 @@ impl<SystemType: System> Debugger<SystemType> {
 
         if !self.bench {
             let (cond, mutex) = &*self.cycle_gate;
-            let _ = cond
+            let _guard = cond
                 .wait_while(mutex.lock().unwrap(), |c| {
                     if *c == 0 {
                         true
@@ -393,6 +964,17 @@ impl<SystemType: System> Debugger<SystemType> {
         }
 
         self.sys.cycle();
+
+        if self.transcript.recording {
+            if let Some(dsp) = self.sys.get_display() {
+                self.transcript.record(self.cycle_count, dsp);
+            }
+        }
+
+        if self.vcd.recording {
+            let chips = get_chip_ports(&self.sys);
+            self.vcd.record(self.cycle_count, &chips, self.sys.get_cpu());
+        }
     }
 
     pub fn read_symbols(&mut self, path: &str) {
@@ -445,6 +1027,39 @@ impl<SystemType: System> Debugger<SystemType> {
     }
 }
 
+// the scan codes of the keys pressed, in order, to type a terminal key on a PS/2 keyboard
+fn get_keyboard_keys(code: event::KeyCode) -> Vec<u16> {
+    let key = match code {
+        event::KeyCode::Char(c) => match ps2::get_char_key(c) {
+            Some((key, true)) => return vec![ps2::LEFT_SHIFT, key],
+            Some((key, false)) => key,
+            None => return Vec::new(),
+        },
+        event::KeyCode::Enter => ps2::ENTER,
+        event::KeyCode::Backspace => ps2::BACKSPACE,
+        event::KeyCode::Tab => ps2::TAB,
+        event::KeyCode::Up => ps2::UP,
+        event::KeyCode::Down => ps2::DOWN,
+        event::KeyCode::Left => ps2::LEFT,
+        event::KeyCode::Right => ps2::RIGHT,
+        event::KeyCode::Home => ps2::HOME,
+        event::KeyCode::End => ps2::END,
+        event::KeyCode::PageUp => ps2::PAGE_UP,
+        event::KeyCode::PageDown => ps2::PAGE_DOWN,
+        event::KeyCode::Insert => ps2::INSERT,
+        event::KeyCode::Delete => ps2::DELETE,
+        _ => return Vec::new(),
+    };
+    vec![key]
+}
+
+// the pins of every VIA and CIA, by name
+fn get_chip_ports<SystemType: System>(sys: &SystemType) -> Vec<(&str, &SystemType::PortsType)> {
+    let vias = sys.get_peripheral_controllers().into_iter().map(|(name, via)| (name, &via.ports));
+    let cias = sys.get_interface_adapters().into_iter().map(|(name, cia)| (name, &cia.ports));
+    vias.chain(cias).collect()
+}
+
 fn get_flag_string(flags: u8) -> String {
     let names = ['C', 'Z', 'I', 'D', 'B', '-', 'O', 'N'];
     (0..8)
@@ -453,6 +1068,36 @@ fn get_flag_string(flags: u8) -> String {
         .collect()
 }
 
+// display contents with the cursor drawn in: underlined, and a solid block while blinking
+fn get_display_rows(dsp: &HD44780U) -> Vec<String> {
+    let mut rows = dsp.get_output();
+
+    if let Some((r, c)) = dsp.get_cursor_cell() {
+        let row = &mut rows[r as usize];
+        *row = row
+            .chars()
+            .enumerate()
+            .map(|(ix, ch)| {
+                if ix != c as usize {
+                    return ch.to_string();
+                }
+                let ch = if dsp.blink_on && dsp.blink_phase { '█' } else { ch };
+                if dsp.cursor_on {
+                    format!("{}{}{}", Attribute::Underlined, ch, Attribute::NoUnderline)
+                } else {
+                    ch.to_string()
+                }
+            })
+            .collect();
+    }
+
+    rows
+}
+
+fn format_lines(lines: &[String]) -> String {
+    lines.iter().map(|line| format!("{}\r\n", line)).collect()
+}
+
 fn show_bytes(source: &[u8], offset: usize) {
     let mut eliding = false;
 
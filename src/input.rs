@@ -0,0 +1,87 @@
+use std::fmt;
+
+use crate::components::{Button, MatrixKeypad};
+
+// a line of a key map or movie without its comment, which starts at a '#' at the start of the
+// line or after whitespace, so that a '#' inside a word such as "keypad:#" is not one
+pub fn strip_comment(line: &str) -> &str {
+    let mut after_space = true;
+    for (ix, c) in line.char_indices() {
+        if c == '#' && after_space {
+            return &line[..ix];
+        }
+        after_space = c.is_whitespace();
+    }
+    line
+}
+
+// something held down by a key
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Switch {
+    Controller(usize, Button), // controller port, and button
+    Keypad(char),              // key label
+    PushButton(usize),         // pushbuttons are numbered in the order they are attached
+}
+
+impl Switch {
+    // a controller button such as "up" or "start", prefixed with "2:" for the second controller,
+    // a keypad key such as "keypad:5", or a pushbutton such as "button:1"
+    pub fn parse(name: &str) -> Option<Switch> {
+        let (prefix, rest) = match name.find(':') {
+            Some(ix) => (&name[..ix], &name[ix + 1..]),
+            None => ("1", name),
+        };
+
+        match prefix {
+            "1" => Some(Switch::Controller(0, Button::parse(rest)?)),
+            "2" => Some(Switch::Controller(1, Button::parse(rest)?)),
+            "keypad" => {
+                let mut chars = rest.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(label), None) => {
+                        MatrixKeypad::find_key(label)?;
+                        Some(Switch::Keypad(label.to_ascii_uppercase()))
+                    }
+                    _ => None,
+                }
+            }
+            "button" => match rest.parse::<usize>() {
+                Ok(n) if n > 0 => Some(Switch::PushButton(n - 1)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Switch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Switch::Controller(port, btn) => write!(f, "{}:{}", port + 1, btn.name()),
+            Switch::Keypad(label) => write!(f, "keypad:{}", label),
+            Switch::PushButton(n) => write!(f, "button:{}", n + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comments_start_at_a_word() {
+        assert_eq!(strip_comment("# a comment"), "");
+        assert_eq!(strip_comment("5 press keypad:#  # the pound key"), "5 press keypad:#  ");
+        assert_eq!(strip_comment("hash keypad:#"), "hash keypad:#");
+    }
+
+    #[test]
+    fn switches_round_trip() {
+        for name in ["1:up", "2:start", "keypad:#", "keypad:D", "button:3"] {
+            assert_eq!(Switch::parse(name).unwrap().to_string(), name);
+        }
+        assert_eq!(Switch::parse("a"), Some(Switch::Controller(0, Button::A)));
+        assert_eq!(Switch::parse("button:0"), None);
+        assert_eq!(Switch::parse("keypad:#5"), None);
+    }
+}
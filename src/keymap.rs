@@ -0,0 +1,183 @@
+use std::fmt;
+use std::fs;
+
+use crate::input::{strip_comment, Switch};
+
+// what a key typed in the run view does
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyAction {
+    Press(Switch), // held down while the key is
+    Pause,
+    Reset,
+    Screenshot,
+    Faster,
+    Slower,
+}
+
+impl KeyAction {
+    // "pause", "reset", "screenshot", "faster", "slower", or a switch to hold down
+    pub fn parse(name: &str) -> Option<KeyAction> {
+        match name {
+            "pause" => Some(KeyAction::Pause),
+            "reset" => Some(KeyAction::Reset),
+            "screenshot" => Some(KeyAction::Screenshot),
+            "faster" => Some(KeyAction::Faster),
+            "slower" => Some(KeyAction::Slower),
+            _ => Some(KeyAction::Press(Switch::parse(name)?)),
+        }
+    }
+}
+
+impl fmt::Display for KeyAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyAction::Press(switch) => write!(f, "{}", switch),
+            KeyAction::Pause => write!(f, "pause"),
+            KeyAction::Reset => write!(f, "reset"),
+            KeyAction::Screenshot => write!(f, "screenshot"),
+            KeyAction::Faster => write!(f, "faster"),
+            KeyAction::Slower => write!(f, "slower"),
+        }
+    }
+}
+
+fn parse_key(name: &str) -> Option<char> {
+    let mut chars = name.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ if name == "space" => Some(' '),
+        _ if name == "hash" => Some('#'),
+        _ => None,
+    }
+}
+
+fn format_key(key: char) -> String {
+    match key {
+        ' ' => "space".to_string(),
+        '#' => "hash".to_string(),
+        c => c.to_string(),
+    }
+}
+
+// host keys and what they do, in the order they were bound.  while a keyboard or a serial console
+// on the board takes typed characters, keys reach the map only with Ctrl held.
+pub struct KeyMap {
+    pub bindings: Vec<(char, KeyAction)>,
+}
+
+impl Default for KeyMap {
+    // switches are on lower case keys and the number and symbol keys, leaving 'p' and 'R' to
+    // pause and reset
+    fn default() -> KeyMap {
+        let mut keys = KeyMap { bindings: Vec::new() };
+        for &(key, action) in [
+            ('w', "up"),
+            ('s', "down"),
+            ('a', "left"),
+            ('d', "right"),
+            ('j', "a"),
+            ('k', "b"),
+            ('l', "select"),
+            (';', "start"),
+            ('u', "x"),
+            ('i', "y"),
+            ('q', "l"),
+            ('e', "r"),
+            ('0', "keypad:0"),
+            ('1', "keypad:1"),
+            ('2', "keypad:2"),
+            ('3', "keypad:3"),
+            ('4', "keypad:4"),
+            ('5', "keypad:5"),
+            ('6', "keypad:6"),
+            ('7', "keypad:7"),
+            ('8', "keypad:8"),
+            ('9', "keypad:9"),
+            ('*', "keypad:*"),
+            ('#', "keypad:#"),
+            ('z', "button:1"),
+            ('x', "button:2"),
+            ('c', "button:3"),
+            ('v', "button:4"),
+            ('p', "pause"),
+            ('R', "reset"),
+            ('o', "screenshot"),
+            ('=', "faster"),
+            ('-', "slower"),
+        ]
+        .iter()
+        {
+            keys.bind(key, KeyAction::parse(action).unwrap());
+        }
+        keys
+    }
+}
+
+impl KeyMap {
+    pub fn get(&self, key: char) -> Option<KeyAction> {
+        self.bindings.iter().find(|(k, _)| *k == key).map(|(_, action)| *action)
+    }
+
+    pub fn bind(&mut self, key: char, action: KeyAction) {
+        match self.bindings.iter_mut().find(|(k, _)| *k == key) {
+            Some(binding) => binding.1 = action,
+            None => self.bindings.push((key, action)),
+        }
+    }
+
+    pub fn unbind(&mut self, key: char) {
+        self.bindings.retain(|(k, _)| *k != key);
+    }
+
+    // reads "<key> <action>" lines over the current bindings.  "<key> none" removes a binding,
+    // the '#' key is named "hash", and '#' starts a comment.
+    pub fn load(&mut self, path: &str) -> Result<(), String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        self.load_str(path, &text)
+    }
+
+    pub fn load_str(&mut self, path: &str, text: &str) -> Result<(), String> {
+        for (ix, line) in text.lines().enumerate() {
+            let line = strip_comment(line);
+            let words: Vec<&str> = line.split_ascii_whitespace().collect();
+            let (key, action) = match words.as_slice() {
+                [] => continue,
+                [key, action] => (*key, *action),
+                _ => return Err(format!("{}:{}: expected '<key> <action>'", path, ix + 1)),
+            };
+
+            let key = parse_key(key).ok_or_else(|| format!("{}:{}: invalid key '{}'", path, ix + 1, key))?;
+            if action == "none" {
+                self.unbind(key);
+            } else {
+                let action = KeyAction::parse(action)
+                    .ok_or_else(|| format!("{}:{}: invalid action '{}'", path, ix + 1, action))?;
+                self.bind(key, action);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn format(&self) -> String {
+        let mut result = String::new();
+        for (key, action) in self.bindings.iter() {
+            result.push_str(&format!("{:6} {}\n", format_key(*key), action));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_map_round_trips() {
+        let keys = KeyMap::default();
+        let mut loaded = KeyMap { bindings: Vec::new() };
+        loaded.load_str("test", &keys.format()).unwrap();
+        assert_eq!(loaded.bindings, keys.bindings);
+        assert_eq!(loaded.get('#'), Some(KeyAction::Press(Switch::Keypad('#'))));
+    }
+}
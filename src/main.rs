@@ -3,36 +3,255 @@ use std::env;
 
 mod components;
 mod debugger;
+mod input;
+mod keymap;
+mod movie;
 mod breadboard_system;
 mod cpu_test_system;
+mod screenshot;
 mod system;
+mod transcript;
+mod vcd;
 
+use components::{SerialLink, HostTerminal, ThreadedConsole, Geometry, BusyCheck, CharacterRom, ControllerType};
 use debugger::Debugger;
-use breadboard_system::BreadboardSystem;
+use keymap::KeyMap;
+use breadboard_system::{BreadboardSystem, BoardOptions, DisplayWiring, KeyboardWiring, PortPin};
 use cpu_test_system::CPUTestSystem;
 use system::System;
 
 fn main() {
     env_logger::init();
 
-    match env::args().nth(1).unwrap_or("breadboard".to_string()).as_str() {
+    let mut args: Vec<String> = env::args().collect();
+    let given = args.clone();
+    let serial = take_option(&mut args, "--serial");
+    let screenshot = take_option(&mut args, "--screenshot");
+    let hold_ms: Option<u64> = take_option(&mut args, "--hold-ms").map(|ms| ms.parse().unwrap());
+
+    let record = take_option(&mut args, "--record");
+    let replay = take_option(&mut args, "--replay");
+
+    let mut keys = KeyMap::default();
+    if let Some(path) = take_option(&mut args, "--keys") {
+        keys.load(path.as_str()).unwrap_or_else(|e| panic!("invalid key map: {}", e));
+    }
+
+    let mut options = BoardOptions::default();
+    if let Some(hz) = take_option(&mut args, "--clock-hz") {
+        options.clock_hz = match hz.parse() {
+            Ok(hz) if hz > 0 && hz <= 1_000_000_000 => hz,
+            _ => panic!("invalid clock rate: {}", hz),
+        };
+    }
+    if let Some(wiring) = take_option(&mut args, "--lcd-wiring") {
+        options.display_wiring = match wiring.as_str() {
+            "8bit" => DisplayWiring::Bus8,
+            "4bit" => DisplayWiring::UpperNibble,
+            w => panic!("invalid lcd wiring: {}", w),
+        };
+    }
+    if let Some(geometry) = take_option(&mut args, "--lcd") {
+        options.display_geometry = Geometry::parse(geometry.as_str())
+            .unwrap_or_else(|| panic!("invalid lcd geometry: {}", geometry));
+    }
+    if let Some(rom) = take_option(&mut args, "--lcd-rom") {
+        options.display_rom = CharacterRom::parse(rom.as_str())
+            .unwrap_or_else(|| panic!("invalid lcd character rom: {}", rom));
+    }
+    if let Some(check) = take_option(&mut args, "--lcd-busy") {
+        options.display_busy_check = match check.as_str() {
+            "off" => BusyCheck::Off,
+            "report" => BusyCheck::Report,
+            "break" => BusyCheck::Break,
+            c => panic!("invalid lcd busy check: {}", c),
+        };
+    }
+    if let Some(wiring) = take_option(&mut args, "--keyboard") {
+        options.keyboard = match wiring.as_str() {
+            "ca1" => Some(KeyboardWiring::Ca1),
+            "sr" => Some(KeyboardWiring::ShiftRegister),
+            "none" => None,
+            w => panic!("invalid keyboard wiring: {}", w),
+        };
+    }
+    if let Some(site) = take_option(&mut args, "--keypad") {
+        options.keypad = Some(PortPin::parse_port(site.as_str()).unwrap_or_else(|| panic!("invalid keypad port: {}", site)));
+    }
+    if let Some(sites) = take_option(&mut args, "--buttons") {
+        options.buttons = sites
+            .split(',')
+            .map(|site| PortPin::parse(site).unwrap_or_else(|| panic!("invalid button pin: {}", site)))
+            .collect();
+    }
+    if let Some(ms) = take_option(&mut args, "--button-bounce-ms") {
+        options.button_bounce_micros = ms.parse::<u32>().unwrap() * 1000;
+    }
+    if let Some(sites) = take_option(&mut args, "--leds") {
+        options.leds = sites
+            .split(',')
+            .map(|site| PortPin::parse(site).unwrap_or_else(|| panic!("invalid led pin: {}", site)))
+            .collect();
+    }
+    if let Some(site) = take_option(&mut args, "--led-bar") {
+        options.led_bar = Some(PortPin::parse_port(site.as_str()).unwrap_or_else(|| panic!("invalid led bar port: {}", site)));
+    }
+    if let Some(site) = take_option(&mut args, "--segments") {
+        options.segments = Some(PortPin::parse_port(site.as_str()).unwrap_or_else(|| panic!("invalid segments port: {}", site)));
+    }
+    if let Some(sites) = take_option(&mut args, "--digits") {
+        options.digits = parse_digits(&sites, options.segments).unwrap_or_else(|e| panic!("invalid digits: {}", e));
+    }
+    if let Some(sites) = take_option(&mut args, "--pull-ups") {
+        options.pull_ups = sites
+            .split(',')
+            .map(|site| PortPin::parse(site).unwrap_or_else(|| panic!("invalid pull-up pin: {}", site)))
+            .collect();
+    }
+    if let Some(sites) = take_option(&mut args, "--pull-downs") {
+        options.pull_downs = sites
+            .split(',')
+            .map(|site| PortPin::parse(site).unwrap_or_else(|| panic!("invalid pull-down pin: {}", site)))
+            .collect();
+    }
+    for (port, name) in ["--controller1", "--controller2"].iter().enumerate() {
+        if let Some(kind) = take_option(&mut args, name) {
+            options.controllers[port] = match kind.as_str() {
+                "none" => None,
+                k => Some(ControllerType::parse(k).unwrap_or_else(|| panic!("invalid controller: {}", k))),
+            };
+        }
+    }
+
+    let board = args.get(1).cloned().unwrap_or("breadboard".to_string());
+    check_options(board.as_str(), &given, &options);
+
+    let settings = Settings { screenshot, keys, record, replay, hold_ms };
+    match board.as_str() {
         "cpu_test" => {
-            let rom = env::args().nth(2).unwrap();
+            let rom = args.get(2).unwrap();
             run(Debugger::new(CPUTestSystem::new(rom.as_str(), 0x400)))
         }
         "breadboard" => {
-            let rom = env::args().nth(2).unwrap();
+            let rom = args.get(2).unwrap();
+            start(BreadboardSystem::new(rom.as_str(), &options), settings, args.get(3));
+        }
+        "graphic" => {
+            let rom = args.get(2).unwrap();
+            start(BreadboardSystem::new_graphic(rom.as_str(), &options), settings, args.get(3));
+        }
+        "expanded" => {
+            let rom = args.get(2).unwrap();
+            let sys = BreadboardSystem::new_expanded(rom.as_str(), &options, open_serial(serial));
+            start(sys, settings, args.get(3));
+        }
+        _ => { panic!("invalid board"); }
+    };
+}
 
-            let mut d = Debugger::new(BreadboardSystem::new(rom.as_str()));
+// debugger settings shared by the boards
+struct Settings {
+    screenshot: Option<String>,
+    keys: KeyMap,
+    record: Option<String>,
+    replay: Option<String>,
+    hold_ms: Option<u64>,
+}
 
-            if let Some(syms) = env::args().nth(3) {
-                d.read_symbols(syms.as_str());
-            }
+fn start<SystemType: System>(sys: SystemType, settings: Settings, syms: Option<&String>) {
+    let mut d = Debugger::new(sys);
+    d.screenshot_path = settings.screenshot;
+    d.keys = settings.keys;
+    start_movie(&mut d, settings.record, settings.replay);
+    if let Some(ms) = settings.hold_ms {
+        d.set_hold_ms(ms);
+    }
 
-            run(d);
-         }
-        _ => { panic!("invalid board"); }
+    if let Some(syms) = syms {
+        d.read_symbols(syms.as_str());
+    }
+
+    run(d);
+}
+
+// every option above, none of which the CPU test system takes
+const OPTIONS: [&str; 23] = [
+    "--serial", "--screenshot", "--hold-ms", "--record", "--replay", "--keys", "--clock-hz", "--lcd-wiring",
+    "--lcd", "--lcd-rom", "--lcd-busy", "--keyboard", "--keypad", "--buttons", "--button-bounce-ms", "--leds",
+    "--led-bar", "--segments", "--digits", "--pull-ups", "--pull-downs", "--controller1", "--controller2",
+];
+
+// options for hardware a board doesn't have are rejected rather than ignored
+fn check_options(board: &str, given: &[String], options: &BoardOptions) {
+    let unsupported: &[&str] = match board {
+        "expanded" => &[],
+        "cpu_test" => &OPTIONS,
+        "graphic" => &["--serial", "--lcd-wiring", "--lcd", "--lcd-rom", "--lcd-busy", "--controller2"],
+        _ => &["--serial"],
     };
+    if let Some(flag) = unsupported.iter().find(|flag| given.iter().any(|a| a == *flag)) {
+        panic!("{} is not supported on the {} board", flag, board);
+    }
+
+    // PA4 is the second chip select
+    if board == "graphic" && matches!(options.keyboard, Some(KeyboardWiring::Ca1)) {
+        panic!("the ca1 keyboard's data pin is a chip select on the graphic board");
+    }
+}
+
+// each digit's common cathode pin, which must be on the VIA driving the segments but not one of
+// the segment pins
+fn parse_digits(sites: &str, segments: Option<PortPin>) -> Result<Vec<PortPin>, String> {
+    let segments = segments.ok_or("the digits need --segments")?;
+    sites
+        .split(',')
+        .map(|site| match PortPin::parse(site) {
+            Some(pin) if pin.via != segments.via => Err(format!("digit pin {} is not on the segments' VIA", site)),
+            Some(pin) if pin.port == segments.port => Err(format!("digit pin {} is a segment pin", site)),
+            Some(pin) => Ok(pin),
+            None => Err(format!("invalid digit pin: {}", site)),
+        })
+        .collect()
+}
+
+// removes "<name> <value>" from the argument list, returning the value
+fn take_option(args: &mut Vec<String>, name: &str) -> Option<String> {
+    let ix = args.iter().position(|a| a == name)?;
+    args.remove(ix);
+    Some(args.remove(ix))
+}
+
+// records input into the file at "--record", or replays the movie at "--replay" from power-on
+fn start_movie<SystemType: System>(dbg: &mut Debugger<SystemType>, record: Option<String>, replay: Option<String>) {
+    if let Some(path) = replay {
+        dbg.movie.load(path.as_str()).unwrap_or_else(|e| panic!("invalid movie: {}", e));
+        dbg.movie.play();
+    }
+    if let Some(path) = record {
+        dbg.movie.start();
+        dbg.movie_path = Some(path);
+    }
+}
+
+// serial console: "stdio" (default), "pty", or "tcp:<port>"
+fn open_serial(spec: Option<String>) -> Box<dyn SerialLink> {
+    match spec.as_deref().unwrap_or("stdio") {
+        "stdio" => Box::new(HostTerminal::new()),
+        "pty" => {
+            let (console, path) = ThreadedConsole::open_pty();
+            println!("serial console on {}", path);
+            Box::new(console)
+        }
+        s if s.starts_with("tcp:") => {
+            let port: u16 = s[4..].parse().unwrap();
+            let console = ThreadedConsole::listen_tcp(port);
+            println!("serial console listening on 127.0.0.1:{}", port);
+            Box::new(console)
+        }
+        s => {
+            panic!("invalid serial console: {}", s);
+        }
+    }
 }
 
 fn run<SystemType: System>(mut dbg: Debugger<SystemType>) {        
@@ -96,11 +315,54 @@ fn run<SystemType: System>(mut dbg: Debugger<SystemType>) {
             "break" | "br" | "b" => dbg.add_breakpoint(words.next().unwrap()),
             "del" => dbg.remove_breakpoint(words.next().unwrap().parse().unwrap()),
             "cpu" => dbg.show_cpu(),
-            "per" => dbg.show_per(),
+            "per" => match words.next() {
+                Some(name) => dbg.show_per_detail(name),
+                None => dbg.show_per(),
+            },
             "zp" | "z" => dbg.show_zp(),
             "stack" | "sta" => dbg.show_stack(),
             "ram" | "mem" | "m" => dbg.show_ram(),
             "display" | "dsp" | "d" => dbg.show_dsp(),
+            "transcript" | "tr" => match words.next() {
+                Some("on") => dbg.start_transcript(),
+                Some("off") => dbg.stop_transcript(),
+                Some("clear") => dbg.clear_transcript(),
+                Some("save") => match words.next() {
+                    Some(path) => dbg.save_transcript(path),
+                    None => println!("usage: transcript save <file>"),
+                },
+                Some("show") | None => dbg.show_transcript(),
+                Some(a) => println!("unknown transcript command: '{}'", a),
+            },
+            "screenshot" | "shot" => match words.next() {
+                Some(path) => dbg.screenshot(path),
+                None => println!("usage: screenshot <file>"),
+            },
+            "movie" => match words.next() {
+                Some("on") => dbg.start_movie(),
+                Some("off") => dbg.stop_movie(),
+                Some("clear") => dbg.clear_movie(),
+                Some("save") => match words.next() {
+                    Some(path) => dbg.save_movie(path),
+                    None => println!("usage: movie save <file>"),
+                },
+                Some("show") | None => dbg.show_movie(),
+                Some(a) => println!("unknown movie command: '{}'", a),
+            },
+            "vcd" => match words.next() {
+                Some("add") => words.for_each(|spec| dbg.add_vcd_signal(spec)),
+                Some("remove") => words.for_each(|spec| dbg.remove_vcd_signal(spec)),
+                Some("on") => dbg.start_vcd(),
+                Some("off") => dbg.stop_vcd(),
+                Some("clear") => dbg.clear_vcd(),
+                Some("save") => match words.next() {
+                    Some(path) => dbg.save_vcd(path),
+                    None => println!("usage: vcd save <file>"),
+                },
+                Some("show") | None => dbg.show_vcd(),
+                Some(a) => println!("unknown vcd command: '{}'", a),
+            },
+            "keys" => dbg.show_keys(),
             "quit" | "q" | "exit" => {
                 return;
             }
@@ -0,0 +1,198 @@
+use std::fs;
+use std::io;
+
+use crate::input::{strip_comment, Switch};
+
+// input that changes what the emulated system does
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputEvent {
+    Press(Switch),
+    Release(Switch),
+    KeyDown(u16), // keyboard scan code
+    KeyUp(u16),
+    Reset,
+}
+
+// a log of input by emulated cycle.  replaying it from power-on repeats the recorded run, up to
+// the cycle at which the movie was saved.
+pub struct Movie {
+    pub recording: bool,
+    pub playing: bool,
+    pub events: Vec<(u64, InputEvent)>, // cycles run before each event, and the event
+    pub end: Option<u64>,               // cycles run when a loaded movie was saved
+    next: usize,                        // next event to play
+}
+
+impl Movie {
+    pub fn new() -> Movie {
+        Movie {
+            recording: false,
+            playing: false,
+            events: Vec::new(),
+            end: None,
+            next: 0,
+        }
+    }
+
+    pub fn start(&mut self) {
+        self.recording = true;
+    }
+
+    pub fn stop(&mut self) {
+        self.recording = false;
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.end = None;
+        self.next = 0;
+    }
+
+    pub fn record(&mut self, cycle: u64, event: InputEvent) {
+        if self.recording {
+            self.events.push((cycle, event));
+        }
+    }
+
+    pub fn play(&mut self) {
+        self.playing = true;
+        self.next = 0;
+    }
+
+    // the next event due once `cycle` cycles have run
+    pub fn next_due(&mut self, cycle: u64) -> Option<InputEvent> {
+        match self.events.get(self.next) {
+            Some(&(at, event)) if at <= cycle => {
+                self.next += 1;
+                Some(event)
+            }
+            _ => None,
+        }
+    }
+
+    // stops playing once every event is played and the end is reached, returning whether there
+    // was an end to reach
+    pub fn reached_end(&mut self, cycle: u64) -> bool {
+        if !self.playing || self.next < self.events.len() {
+            return false;
+        }
+
+        match self.end {
+            Some(end) if end > cycle => false,
+            end => {
+                self.playing = false;
+                end.is_some()
+            }
+        }
+    }
+
+    // the events, followed by `end`: the cycles run so far
+    pub fn format(&self, end: u64) -> String {
+        let mut result = String::new();
+        for (cycle, event) in self.events.iter() {
+            result.push_str(&match event {
+                InputEvent::Press(switch) => format!("{} press {}\n", cycle, switch),
+                InputEvent::Release(switch) => format!("{} release {}\n", cycle, switch),
+                InputEvent::KeyDown(code) => format!("{} keydown {:x}\n", cycle, code),
+                InputEvent::KeyUp(code) => format!("{} keyup {:x}\n", cycle, code),
+                InputEvent::Reset => format!("{} reset\n", cycle),
+            });
+        }
+        result.push_str(&format!("{} end\n", end));
+        result
+    }
+
+    pub fn save(&self, path: &str, end: u64) -> io::Result<()> {
+        fs::write(path, self.format(end))
+    }
+
+    // reads "<cycle> press|release <switch>", "<cycle> keydown|keyup <hex scan code>" and
+    // "<cycle> reset" lines in cycle order, then an optional "<cycle> end".  '#' starts a comment.
+    pub fn load(&mut self, path: &str) -> Result<(), String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        self.load_str(path, &text)
+    }
+
+    pub fn load_str(&mut self, path: &str, text: &str) -> Result<(), String> {
+        let mut events = Vec::new();
+        let mut end = None;
+        for (ix, line) in text.lines().enumerate() {
+            let line = strip_comment(line);
+            let words: Vec<&str> = line.split_ascii_whitespace().collect();
+            let error = || format!("{}:{}: invalid event '{}'", path, ix + 1, line.trim());
+
+            if end.is_some() && !words.is_empty() {
+                return Err(format!("{}:{}: event after end", path, ix + 1));
+            }
+
+            let (cycle, event) = match words.as_slice() {
+                [] => continue,
+                [cycle, "end"] => {
+                    end = Some(cycle.parse().map_err(|_| error())?);
+                    continue;
+                }
+                [cycle, "reset"] => (*cycle, InputEvent::Reset),
+                [cycle, kind @ ("press" | "release"), switch] => {
+                    let switch = Switch::parse(switch).ok_or_else(error)?;
+                    match *kind {
+                        "press" => (*cycle, InputEvent::Press(switch)),
+                        _ => (*cycle, InputEvent::Release(switch)),
+                    }
+                }
+                [cycle, kind @ ("keydown" | "keyup"), code] => {
+                    let code = u16::from_str_radix(code, 16).map_err(|_| error())?;
+                    match *kind {
+                        "keydown" => (*cycle, InputEvent::KeyDown(code)),
+                        _ => (*cycle, InputEvent::KeyUp(code)),
+                    }
+                }
+                _ => return Err(error()),
+            };
+
+            let cycle: u64 = cycle.parse().map_err(|_| error())?;
+            if matches!(events.last(), Some(&(last, _)) if cycle < last) {
+                return Err(format!("{}:{}: events out of order", path, ix + 1));
+            }
+            events.push((cycle, event));
+        }
+
+        self.events = events;
+        self.end = end;
+        self.next = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::Button;
+
+    #[test]
+    fn movie_round_trips() {
+        let mut movie = Movie::new();
+        movie.events = vec![
+            (10, InputEvent::Press(Switch::Keypad('#'))),
+            (20, InputEvent::Release(Switch::Keypad('#'))),
+            (30, InputEvent::Press(Switch::Controller(1, Button::Start))),
+            (40, InputEvent::KeyDown(0xE075)),
+            (50, InputEvent::KeyUp(0xE075)),
+            (60, InputEvent::Reset),
+        ];
+
+        let mut loaded = Movie::new();
+        loaded.load_str("test", &movie.format(70)).unwrap();
+        assert_eq!(loaded.events, movie.events);
+        assert_eq!(loaded.end, Some(70));
+    }
+
+    #[test]
+    fn a_cleared_movie_has_no_end() {
+        let mut movie = Movie::new();
+        movie.load_str("test", "10 reset\n70 end\n").unwrap();
+        movie.clear();
+        movie.play();
+        assert!(!movie.reached_end(0));
+        assert!(!movie.playing);
+    }
+}
@@ -0,0 +1,115 @@
+use std::fs::File;
+use std::io::BufWriter;
+
+use crate::components::{glcd, HD44780U, KS0108};
+
+// each dot is drawn as a square, with a gap to its neighbours
+const DOT_SIZE: usize = 3;
+const DOT_PITCH: usize = 4;
+
+// extra space between characters and between rows, and around the panel
+const CHAR_GAP: usize = 2;
+const ROW_GAP: usize = 4;
+const BORDER: usize = 12;
+
+const BACKLIGHT: [u8; 3] = [0x8e, 0xb8, 0x1c];
+const DOT_OFF: [u8; 3] = [0x84, 0xac, 0x1a];
+const DOT_ON: [u8; 3] = [0x1e, 0x2a, 0x0a];
+
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>, // RGB, row by row
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, color: [u8; 3]) -> Image {
+        Image {
+            width,
+            height,
+            pixels: color.iter().cloned().cycle().take(width * height * 3).collect(),
+        }
+    }
+
+    pub fn fill_rect(&mut self, x: usize, y: usize, w: usize, h: usize, color: [u8; 3]) {
+        for py in y..y + h {
+            for px in x..x + w {
+                let ix = (py * self.width + px) * 3;
+                self.pixels[ix..ix + 3].copy_from_slice(&color);
+            }
+        }
+    }
+
+    pub fn write_png(&self, path: &str) -> Result<(), png::EncodingError> {
+        let file = BufWriter::new(File::create(path)?);
+
+        let mut encoder = png::Encoder::new(file, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::RGB);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)
+    }
+}
+
+// draws the panel dot by dot, as 5x8 or 5x10 characters
+pub fn render_hd44780(dsp: &HD44780U) -> Image {
+    let cell_height = dsp.get_cell_height();
+    let char_width = 5 * DOT_PITCH + CHAR_GAP;
+    let char_height = cell_height * DOT_PITCH + ROW_GAP;
+    let columns = dsp.geometry.columns as usize;
+    let rows = dsp.geometry.rows as usize;
+
+    let mut img = Image::new(
+        2 * BORDER + columns * char_width - CHAR_GAP,
+        2 * BORDER + rows * char_height - ROW_GAP,
+        BACKLIGHT,
+    );
+
+    let cursor = dsp.get_cursor_addr();
+    for row in 0..rows {
+        for column in 0..columns {
+            let mut glyph = vec![0u8; cell_height];
+            if let Some(addr) = dsp.get_cell_addr(row as u8, column as u8) {
+                glyph = dsp.get_glyph(dsp.ddram[addr as usize]);
+                if cursor == Some(addr) {
+                    if dsp.cursor_on {
+                        glyph[cell_height - 1] = 0x1F;
+                    }
+                    if dsp.blink_on && dsp.blink_phase {
+                        glyph = vec![0x1F; cell_height];
+                    }
+                }
+            }
+
+            let x = BORDER + column * char_width;
+            let y = BORDER + row * char_height;
+            for (dy, dots) in glyph.iter().enumerate() {
+                for dx in 0..5 {
+                    let color = if dots & (0x10 >> dx) != 0 { DOT_ON } else { DOT_OFF };
+                    img.fill_rect(x + dx * DOT_PITCH, y + dy * DOT_PITCH, DOT_SIZE, DOT_SIZE, color);
+                }
+            }
+        }
+    }
+
+    img
+}
+
+// draws the panel pixel by pixel, with no gaps between characters
+pub fn render_ks0108(glcd: &KS0108) -> Image {
+    let mut img = Image::new(
+        2 * BORDER + glcd::WIDTH * DOT_PITCH,
+        2 * BORDER + glcd::HEIGHT * DOT_PITCH,
+        BACKLIGHT,
+    );
+
+    for y in 0..glcd::HEIGHT {
+        for x in 0..glcd::WIDTH {
+            let color = if glcd.get_pixel(x, y) { DOT_ON } else { DOT_OFF };
+            img.fill_rect(BORDER + x * DOT_PITCH, BORDER + y * DOT_PITCH, DOT_SIZE, DOT_SIZE, color);
+        }
+    }
+
+    img
+}
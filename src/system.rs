@@ -1,15 +1,42 @@
 use crate::components::*;
 
+// emulated time after a number of cycles, worked out from the start so it doesn't drift when the
+// clock period isn't a whole number of nanoseconds
+pub fn cycles_to_nanos(cycles: u64, clock_hz: u32) -> u64 {
+    (cycles as u128 * 1_000_000_000 / clock_hz as u128) as u64
+}
+
 pub trait System {
     type BusType : Bus;
     type PortsType : Ports;
 
     fn is_halted(&self) -> bool;
+    fn get_clock_hz(&self) -> u32;
     fn get_cpu(&self) -> &W65C02S<Self::BusType>;
     fn get_display(&mut self) -> Option<&mut HD44780U>;
+    fn get_graphic_display(&mut self) -> Option<&mut KS0108>;
     fn get_ram(&self) -> &RAM;
-    fn get_controller(&mut self) -> Option<&mut SNESController>;
-    fn get_peripheral_controller(&self) -> Option<&W65C22<Self::PortsType>>;
+    fn get_controller(&mut self, port: usize) -> Option<&mut dyn Controller>;
+    fn get_keyboard(&mut self) -> Option<&mut PS2Keyboard>;
+    fn get_keypad(&mut self) -> Option<&mut MatrixKeypad>;
+    fn get_push_button(&mut self, n: usize) -> Option<&mut PushButton>;
+    fn get_lights(&mut self) -> Vec<&mut Lights>;
+    fn get_serial(&mut self) -> Option<&mut W65C51>;
+    fn peek_serial(&self) -> Option<&W65C51>;
+    fn get_peripheral_controllers(&self) -> Vec<(&str, &W65C22<Self::PortsType>)>;
+    fn get_interface_adapters(&self) -> Vec<(&str, &MOS6526<Self::PortsType>)>;
     fn peek(&mut self, addr: u16) -> u8;
+    fn reset(&mut self);
     fn cycle(&mut self);
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emulated_time_does_not_drift() {
+        assert_eq!(cycles_to_nanos(3_000_000, 3_000_000), 1_000_000_000);
+        assert_eq!(cycles_to_nanos(1, 3_000_000), 333);
+        assert_eq!(cycles_to_nanos(u32::MAX as u64 * 7, 7), u32::MAX as u64 * 1_000_000_000);
+    }
+}
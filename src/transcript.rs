@@ -0,0 +1,72 @@
+use std::fs;
+use std::io;
+
+use crate::components::HD44780U;
+use crate::system::cycles_to_nanos;
+
+// a log of what the LCD showed, one entry per change of its visible text
+pub struct Transcript {
+    pub recording: bool,
+    pub entries: Vec<(u64, Vec<String>)>, // emulated cycle of each change, and the rows shown
+    clock_hz: u32,
+    revision: Option<u64>, // display revision last looked at
+}
+
+impl Transcript {
+    pub fn new(clock_hz: u32) -> Transcript {
+        Transcript {
+            recording: false,
+            entries: Vec::new(),
+            clock_hz,
+            revision: None,
+        }
+    }
+
+    pub fn start(&mut self) {
+        self.recording = true;
+        self.revision = None;
+    }
+
+    pub fn stop(&mut self) {
+        self.recording = false;
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.revision = None;
+    }
+
+    pub fn record(&mut self, cycle: u64, dsp: &HD44780U) {
+        if !self.recording || self.revision == Some(dsp.revision) {
+            return;
+        }
+        self.revision = Some(dsp.revision);
+
+        // changes such as cursor moves and blinking leave the text as it was
+        let rows = dsp.get_output();
+        if self.entries.last().map(|(_, last)| last) != Some(&rows) {
+            self.entries.push((cycle, rows));
+        }
+    }
+
+    pub fn format(&self) -> String {
+        let mut result = String::new();
+        for (cycle, rows) in self.entries.iter() {
+            let nanos = cycles_to_nanos(*cycle, self.clock_hz);
+            result.push_str(&format!(
+                "[{}.{:09}s] cycle {}\n",
+                nanos / 1_000_000_000,
+                nanos % 1_000_000_000,
+                cycle
+            ));
+            for row in rows {
+                result.push_str(&format!("|{}|\n", row));
+            }
+        }
+        result
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        fs::write(path, self.format())
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+
+use crate::components::*;
+use crate::system::cycles_to_nanos;
+
+// a signal to capture
+#[derive(Clone, Debug, PartialEq)]
+pub enum Signal {
+    Pin(String, Pin),   // a VIA's or CIA's pin, by the chip's name
+    Port(String, Port), // all eight pins of a port
+    Irq,                // the CPU's IRQB input, active low
+    Data(u16, String),  // the last value read from or written to an address, and its name
+    Read(u16, String),  // high for the cycles in which an address is read
+    Write(u16, String), // high for the cycles in which an address is written
+}
+
+impl Signal {
+    // e.g. "via0:pa3", "via0:ca1", "via1:pb", "cia0:sp", "irq", "$6000", or a symbol.  an address gives its
+    // data, read and write signals.
+    pub fn parse(s: &str, symbols: &HashMap<String, u16>) -> Option<Vec<Signal>> {
+        if s == "irq" {
+            return Some(vec![Signal::Irq]);
+        }
+
+        if let Some(ix) = s.find(':') {
+            let (chip, pin) = (s[..ix].to_string(), &s[ix + 1..]);
+            return match pin {
+                "pa" => Some(vec![Signal::Port(chip, Port::A)]),
+                "pb" => Some(vec![Signal::Port(chip, Port::B)]),
+                pin => Some(vec![Signal::Pin(chip, Pin::parse(pin)?)]),
+            };
+        }
+
+        let (addr, name) = match symbols.get(s) {
+            Some(addr) => (*addr, s.to_string()),
+            None => {
+                let addr = u16::from_str_radix(s.trim_start_matches('$'), 16).ok()?;
+                (addr, format!("a{:04x}", addr))
+            }
+        };
+        Some(vec![
+            Signal::Data(addr, name.clone()),
+            Signal::Read(addr, format!("{}_rd", name)),
+            Signal::Write(addr, format!("{}_wr", name)),
+        ])
+    }
+
+    pub fn width(&self) -> usize {
+        match self {
+            Signal::Port(..) | Signal::Data(..) => 8,
+            _ => 1,
+        }
+    }
+
+    // the VCD scope holding the signal, and its name there
+    fn scope_and_name(&self) -> (String, String) {
+        match self {
+            Signal::Pin(chip, pin) => (chip.clone(), pin.to_string().to_lowercase()),
+            Signal::Port(chip, Port::A) => (chip.clone(), "pa".to_string()),
+            Signal::Port(chip, Port::B) => (chip.clone(), "pb".to_string()),
+            Signal::Irq => ("cpu".to_string(), "irqb".to_string()),
+            Signal::Data(_, name) | Signal::Read(_, name) | Signal::Write(_, name) => ("bus".to_string(), name.clone()),
+        }
+    }
+
+    // the signal's value after a cycle, or None to keep the last value
+    fn sample<P: Ports, B: Bus>(&self, chips: &[(&str, &P)], cpu: &W65C02S<B>) -> Option<u8> {
+        let levels = |chip: &str| chips.iter().find(|(name, _)| *name == chip).map(|(_, ports)| ports.peek_pins());
+        match self {
+            Signal::Pin(chip, pin) => Some(pin.is_high(levels(chip)?) as u8),
+            Signal::Port(chip, port) => Some(port_byte(*port, levels(chip)?)),
+            Signal::Irq => Some(!cpu.interrupt as u8),
+            Signal::Data(addr, _) => cpu.accesses.iter().rev().find(|a| a.addr == *addr).map(|a| a.val),
+            Signal::Read(addr, _) => Some(cpu.accesses.iter().any(|a| a.addr == *addr && !a.write) as u8),
+            Signal::Write(addr, _) => Some(cpu.accesses.iter().any(|a| a.addr == *addr && a.write) as u8),
+        }
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (scope, name) = self.scope_and_name();
+        write!(f, "{}.{}", scope, name)
+    }
+}
+
+// VCD identifiers are strings of the printable characters
+fn get_identifier(mut ix: usize) -> String {
+    let mut id = String::new();
+    loop {
+        id.push((b'!' + (ix % 94) as u8) as char);
+        ix /= 94;
+        if ix == 0 {
+            return id;
+        }
+        ix -= 1;
+    }
+}
+
+fn format_value(value: u8, width: usize, id: &str) -> String {
+    if width == 1 {
+        format!("{}{}", value, id)
+    } else {
+        format!("b{:0width$b} {}", value, id, width = width)
+    }
+}
+
+// a logic analyzer: changes to the chosen signals, by emulated cycle, written out as a VCD file
+pub struct Capture {
+    pub recording: bool,
+    pub signals: Vec<Signal>,
+    pub changes: Vec<(u64, usize, u8)>, // emulated cycle, signal, and its new value
+    clock_hz: u32,
+    values: Vec<Option<u8>>, // each signal's last recorded value
+}
+
+impl Capture {
+    pub fn new(clock_hz: u32) -> Capture {
+        Capture {
+            recording: false,
+            signals: Vec::new(),
+            changes: Vec::new(),
+            clock_hz,
+            values: Vec::new(),
+        }
+    }
+
+    // false if the signal was already being captured
+    pub fn add(&mut self, signal: Signal) -> bool {
+        if self.signals.contains(&signal) {
+            return false;
+        }
+        self.signals.push(signal);
+        self.values.push(None);
+        true
+    }
+
+    // recorded changes refer to signals by position, so they go too
+    pub fn remove(&mut self, signal: &Signal) -> bool {
+        match self.signals.iter().position(|s| s == signal) {
+            Some(ix) => {
+                self.signals.remove(ix);
+                self.values.remove(ix);
+                self.clear();
+                true
+            }
+            None => false,
+        }
+    }
+
+    // every signal's value is recorded again when capture restarts
+    pub fn start(&mut self) {
+        self.recording = true;
+        self.values.iter_mut().for_each(|v| *v = None);
+    }
+
+    pub fn stop(&mut self) {
+        self.recording = false;
+    }
+
+    pub fn clear(&mut self) {
+        self.changes.clear();
+        self.values.iter_mut().for_each(|v| *v = None);
+    }
+
+    pub fn record<P: Ports, B: Bus>(&mut self, cycle: u64, chips: &[(&str, &P)], cpu: &W65C02S<B>) {
+        if !self.recording {
+            return;
+        }
+
+        for (ix, signal) in self.signals.iter().enumerate() {
+            if let Some(value) = signal.sample(chips, cpu) {
+                if self.values[ix] != Some(value) {
+                    self.values[ix] = Some(value);
+                    self.changes.push((cycle, ix, value));
+                }
+            }
+        }
+    }
+
+    pub fn format(&self) -> String {
+        let ids: Vec<String> = (0..self.signals.len()).map(get_identifier).collect();
+
+        let mut result = String::new();
+        result.push_str("$version emu $end\n");
+        result.push_str("$timescale 1ns $end\n");
+
+        // signals grouped by scope, in the order each scope was first used
+        let mut scopes: Vec<(String, Vec<(usize, String)>)> = Vec::new();
+        for (ix, signal) in self.signals.iter().enumerate() {
+            let (scope, name) = signal.scope_and_name();
+            match scopes.iter_mut().find(|(s, _)| *s == scope) {
+                Some((_, vars)) => vars.push((ix, name)),
+                None => scopes.push((scope, vec![(ix, name)])),
+            }
+        }
+        result.push_str("$scope module emu $end\n");
+        for (scope, vars) in scopes.iter() {
+            result.push_str(&format!("$scope module {} $end\n", scope));
+            for (ix, name) in vars.iter() {
+                result.push_str(&format!("$var wire {} {} {} $end\n", self.signals[*ix].width(), ids[*ix], name));
+            }
+            result.push_str("$upscope $end\n");
+        }
+        result.push_str("$upscope $end\n$enddefinitions $end\n");
+
+        // signals are unknown until their first change
+        let mut time = None;
+        for (cycle, ix, value) in self.changes.iter() {
+            if time != Some(*cycle) {
+                result.push_str(&format!("#{}\n", cycles_to_nanos(*cycle, self.clock_hz)));
+                if time.is_none() {
+                    result.push_str("$dumpvars\n");
+                    for (ix, signal) in self.signals.iter().enumerate() {
+                        match signal.width() {
+                            1 => result.push_str(&format!("x{}\n", ids[ix])),
+                            _ => result.push_str(&format!("bx {}\n", ids[ix])),
+                        }
+                    }
+                    result.push_str("$end\n");
+                }
+                time = Some(*cycle);
+            }
+            result.push_str(&format_value(*value, self.signals[*ix].width(), &ids[*ix]));
+            result.push('\n');
+        }
+        result
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        fs::write(path, self.format())
+    }
+}